@@ -1,29 +0,0 @@
-pub fn process_part_1(input: &str) -> String {
-    todo!("Part 1");
-}
-
-pub fn process_part_2(input: &str) -> String {
-    todo!("Part 2");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = include_str!("../test_input.txt");
-
-    #[test]
-    fn test_part_1() {
-        let result = process_part_1(INPUT);
-
-        assert_eq!(result, "");
-    }
-
-    #[test]
-    fn test_part_2() {
-        let result = process_part_2(INPUT);
-
-        assert_eq!(result, "");
-    }
-}
-
@@ -0,0 +1,53 @@
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day01;
+
+fn totals(input: &str) -> Vec<u32> {
+    input
+        .split("\n\n")
+        .map(|elf| elf.lines().map(|line| line.parse::<u32>().unwrap()).sum())
+        .collect()
+}
+
+impl Fuzz for Day01 {
+    fn generate(rng: &mut StdRng) -> String {
+        let elves: Vec<String> = (0..rng.gen_range(1..=20))
+            .map(|_| {
+                (0..rng.gen_range(1..=8))
+                    .map(|_| rng.gen_range(1..=60_000).to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+            .collect();
+
+        elves.join("\n\n") + "\n"
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        totals(input).into_iter().max().unwrap().into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        let mut totals = totals(input);
+
+        let mut top_three = 0;
+
+        // Take the largest three times over rather than sorting
+        for _ in 0..3.min(totals.len()) {
+            let (i, &largest) = totals.iter().enumerate().max_by_key(|&(_, total)| total).unwrap();
+
+            top_three += largest;
+            totals.remove(i);
+        }
+
+        top_three.into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day01>(2000);
+}
@@ -1,35 +1,44 @@
-pub fn process_part_1(input: &str) -> String {
-    let result: u32 = input
-        .split("\n\n")
-        .map(|elf_load| {
-            elf_load
-                .lines()
-                .map(|item| item.parse::<u32>().unwrap())
-                .sum()
-        })
-        .max()
-        .unwrap();
-
-    return result.to_string();
-}
+#[cfg(test)]
+mod fuzz;
+
+use aoc_core::parse::{blocks, finish, lines};
+use aoc_core::{Answer, ParseError, Solution};
+use nom::character::complete;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        finish(input, blocks(lines(complete::u32)))
+    }
+
+    fn part_1(elf_loads: &Self::Parsed) -> Answer {
+        let result: u32 = elf_loads
+            .iter()
+            .map(|elf_load| elf_load.iter().sum())
+            .max()
+            .unwrap();
 
-pub fn process_part_2(input: &str) -> String {
-    let mut result: Vec<u32> = input
-        .split("\n\n")
-        .map(|elf_load| {
-            elf_load
-                .lines()
-                .map(|item| item.parse::<u32>().unwrap())
-                .sum()
-        })
-        .collect();
-
-    result.sort_by(|a, b| b.cmp(a));
-
-    let tri_max_sum: u32 = result
-        .iter()
-        .take(3)
-        .sum();
-
-    return tri_max_sum.to_string();
+        return result.into();
+    }
+
+    fn part_2(elf_loads: &Self::Parsed) -> Answer {
+        let mut result: Vec<u32> = elf_loads
+            .iter()
+            .map(|elf_load| elf_load.iter().sum())
+            .collect();
+
+        result.sort_by(|a, b| b.cmp(a));
+
+        let tri_max_sum: u32 = result
+            .iter()
+            .take(3)
+            .sum();
+
+        return tri_max_sum.into();
+    }
 }
+
+aoc_core::examples!();
@@ -0,0 +1,66 @@
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day02;
+
+// Rock, paper and scissors as 0, 1 and 2, where each shape beats the one before it
+fn shape(c: char) -> u32 {
+    match c {
+        'A' | 'X' => 0,
+        'B' | 'Y' => 1,
+        'C' | 'Z' => 2,
+        _ => unreachable!(),
+    }
+}
+
+fn outcome(opponent: u32, response: u32) -> u32 {
+    if response == opponent {
+        3
+    } else if response == (opponent + 1) % 3 {
+        6
+    } else {
+        0
+    }
+}
+
+fn rounds(input: &str) -> impl Iterator<Item = (u32, u32)> + '_ {
+    input.lines().map(|line| {
+        let mut chars = line.chars();
+
+        (shape(chars.next().unwrap()), shape(chars.nth(1).unwrap()))
+    })
+}
+
+impl Fuzz for Day02 {
+    fn generate(rng: &mut StdRng) -> String {
+        (0..rng.gen_range(1..=100))
+            .map(|_| format!("{} {}\n", rng.gen_range('A'..='C'), rng.gen_range('X'..='Z')))
+            .collect()
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        rounds(input)
+            .map(|(opponent, response)| response + 1 + outcome(opponent, response))
+            .sum::<u32>()
+            .into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        // Try every response and keep the one that ends the round the way the guide says
+        rounds(input)
+            .map(|(opponent, wanted)| {
+                let response = (0..3).find(|&response| outcome(opponent, response) == wanted * 3).unwrap();
+
+                response + 1 + wanted * 3
+            })
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day02>(2000);
+}
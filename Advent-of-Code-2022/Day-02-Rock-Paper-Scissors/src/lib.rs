@@ -1,43 +1,67 @@
+#[cfg(test)]
+mod fuzz;
+
 use std::collections::HashMap;
 
-pub fn process_part_1(input: &str) -> String {
-    let scoring_sheet: HashMap<&str, u32>= HashMap::from([
-        ("B X", 1),
-        ("C Y", 2),
-        ("A Z", 3),
-        ("A X", 4),
-        ("B Y", 5),
-        ("C Z", 6),
-        ("C X", 7),
-        ("A Y", 8),
-        ("B Z", 9),
-    ]);
-
-    let result: u32 = input
-        .lines()
-        .map(|line| scoring_sheet.get(line).unwrap())
-        .sum();
-
-    return result.to_string();
-}
+use aoc_core::parse::{finish, lines};
+use aoc_core::{Answer, ParseError, Solution};
+use nom::character::complete::{char, one_of};
+use nom::error::context;
+use nom::sequence::separated_pair;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<(char, char)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let opponent = context("A, B or C", one_of("ABC"));
+        let response = context("X, Y or Z", one_of("XYZ"));
+
+        finish(input, lines(separated_pair(opponent, char(' '), response)))
+    }
 
-pub fn process_part_2(input: &str) -> String {
-    let scoring_sheet: HashMap<&str, u32> = HashMap::from([
-        ("B X", 1),
-        ("C X", 2),
-        ("A X", 3),
-        ("A Y", 4),
-        ("B Y", 5),
-        ("C Y", 6),
-        ("C Z", 7),
-        ("A Z", 8),
-        ("B Z", 9),
-    ]);
-
-    let result: u32 = input
-        .lines()
-        .map(|line| scoring_sheet.get(line).unwrap())
-        .sum();
-
-    return result.to_string();
+    fn part_1(rounds: &Self::Parsed) -> Answer {
+        let scoring_sheet: HashMap<(char, char), u32> = HashMap::from([
+            (('B', 'X'), 1),
+            (('C', 'Y'), 2),
+            (('A', 'Z'), 3),
+            (('A', 'X'), 4),
+            (('B', 'Y'), 5),
+            (('C', 'Z'), 6),
+            (('C', 'X'), 7),
+            (('A', 'Y'), 8),
+            (('B', 'Z'), 9),
+        ]);
+
+        let result: u32 = rounds
+            .iter()
+            .map(|round| scoring_sheet.get(round).unwrap())
+            .sum();
+
+        return result.into();
+    }
+
+    fn part_2(rounds: &Self::Parsed) -> Answer {
+        let scoring_sheet: HashMap<(char, char), u32> = HashMap::from([
+            (('B', 'X'), 1),
+            (('C', 'X'), 2),
+            (('A', 'X'), 3),
+            (('A', 'Y'), 4),
+            (('B', 'Y'), 5),
+            (('C', 'Y'), 6),
+            (('C', 'Z'), 7),
+            (('A', 'Z'), 8),
+            (('B', 'Z'), 9),
+        ]);
+
+        let result: u32 = rounds
+            .iter()
+            .map(|round| scoring_sheet.get(round).unwrap())
+            .sum();
+
+        return result.into();
+    }
 }
+
+aoc_core::examples!();
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::seq::SliceRandom;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day03;
+
+fn priority(item: char) -> u32 {
+    match item {
+        'a'..='z' => item as u32 - 'a' as u32 + 1,
+        'A'..='Z' => item as u32 - 'A' as u32 + 27,
+        _ => unreachable!(),
+    }
+}
+
+fn items(items: &str) -> HashSet<char> {
+    items.chars().collect()
+}
+
+// The one item every set has in common
+fn common(sets: &[HashSet<char>]) -> char {
+    let common: Vec<&char> = sets[0]
+        .iter()
+        .filter(|item| sets.iter().all(|set| set.contains(item)))
+        .collect();
+
+    assert_eq!(common.len(), 1);
+
+    *common[0]
+}
+
+// Each elf of a group packs from its own letters, so only the badge is shared by all three, and each compartment
+// takes from its own half of those letters, so only one item ends up in both
+fn rucksack(rng: &mut StdRng, letters: &[char], badge: char) -> String {
+    let shared = if rng.gen_bool(0.2) {badge} else {letters[0]};
+    let (first, second) = letters[1..].split_at(letters.len() / 2);
+
+    let size = rng.gen_range(2..=12);
+
+    let mut compartments = [vec![shared], vec![shared]];
+
+    if shared != badge {
+        compartments[rng.gen_range(0..2)].push(badge);
+    }
+
+    for (compartment, letters) in compartments.iter_mut().zip([first, second]) {
+        while compartment.len() < size {
+            compartment.push(*letters.choose(rng).unwrap());
+        }
+
+        compartment.shuffle(rng);
+    }
+
+    compartments.concat().into_iter().collect()
+}
+
+impl Fuzz for Day03 {
+    fn generate(rng: &mut StdRng) -> String {
+        let mut rucksacks = String::new();
+
+        for _ in 0..rng.gen_range(1..=10) {
+            let mut letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+
+            letters.shuffle(rng);
+
+            let badge = letters.pop().unwrap();
+
+            letters.chunks(letters.len() / 3).for_each(|letters| {
+                rucksacks += &rucksack(rng, letters, badge);
+                rucksacks += "\n";
+            });
+        }
+
+        rucksacks
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        input
+            .lines()
+            .map(|line| {
+                let (first, second) = line.split_at(line.len() / 2);
+
+                priority(common(&[items(first), items(second)]))
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        let lines: Vec<&str> = input.lines().collect();
+
+        lines
+            .chunks(3)
+            .map(|group| priority(common(&group.iter().map(|line| items(line)).collect::<Vec<_>>())))
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day03>(2000);
+}
@@ -1,49 +1,65 @@
+#[cfg(test)]
+mod fuzz;
+
 use std::collections::HashMap;
 
-pub fn process_part_1(input: &str) -> String {
-    let priorities: HashMap<char, usize> = ('a'..='z')
+use aoc_core::parse::{finish, lines};
+use aoc_core::{Answer, ParseError, Solution};
+use nom::character::complete::alpha1;
+use nom::Parser;
+
+fn priorities() -> HashMap<char, usize> {
+    ('a'..='z')
         .chain('A'..='Z')
-        .into_iter()
         .enumerate()
         .map(|(i, c)| (c, i + 1))
-        .collect();
-
-    let result: usize = input
-        .lines()
-        .map(|line| line.split_at(line.len() / 2))
-        .map(|(first, second)| {
-            first
-                .chars()
-                .find(|c| second.contains(*c))
-                .unwrap()
-        })
-        .map(|c| *priorities.get(&c).unwrap())
-        .sum();
-
-    return result.to_string();
+        .collect()
 }
 
-pub fn process_part_2(input: &str) -> String {
-    let priorities: HashMap<char, usize> = ('a'..='z')
-        .chain('A'..='Z')
-        .into_iter()
-        .enumerate()
-        .map(|(i, c)| (c, i + 1))
-        .collect();
-
-    let result: usize = input
-        .lines()
-        .collect::<Vec<_>>()
-        .chunks(3)
-        .map(|chunk| {
-            chunk[0]
-                .chars()
-                .find(|char| chunk[1].contains(*char) && chunk[2].contains(*char))
-                .unwrap()
-
-        })
-        .map(|c| *priorities.get(&c).unwrap())
-        .sum();
-
-    return result.to_string();
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        finish(input, lines(alpha1.map(String::from)))
+    }
+
+    fn part_1(rucksacks: &Self::Parsed) -> Answer {
+        let priorities = priorities();
+
+        let result: usize = rucksacks
+            .iter()
+            .map(|line| line.split_at(line.len() / 2))
+            .map(|(first, second)| {
+                first
+                    .chars()
+                    .find(|c| second.contains(*c))
+                    .unwrap()
+            })
+            .map(|c| *priorities.get(&c).unwrap())
+            .sum();
+
+        return result.into();
+    }
+
+    fn part_2(rucksacks: &Self::Parsed) -> Answer {
+        let priorities = priorities();
+
+        let result: usize = rucksacks
+            .chunks(3)
+            .map(|chunk| {
+                chunk[0]
+                    .chars()
+                    .find(|char| chunk[1].contains(*char) && chunk[2].contains(*char))
+                    .unwrap()
+
+            })
+            .map(|c| *priorities.get(&c).unwrap())
+            .sum();
+
+        return result.into();
+    }
 }
+
+aoc_core::examples!();
@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day04;
+
+// Every section each elf of a pair is assigned, spelled out
+fn pairs(input: &str) -> Vec<(HashSet<u32>, HashSet<u32>)> {
+    let sections = |range: &str| {
+        let (start, end) = range.split_once('-').unwrap();
+
+        (start.parse().unwrap()..=end.parse().unwrap()).collect()
+    };
+
+    input
+        .lines()
+        .map(|line| {
+            let (first, second) = line.split_once(',').unwrap();
+
+            (sections(first), sections(second))
+        })
+        .collect()
+}
+
+impl Fuzz for Day04 {
+    fn generate(rng: &mut StdRng) -> String {
+        let range = |rng: &mut StdRng| {
+            let start = rng.gen_range(1..=30);
+
+            format!("{start}-{}", rng.gen_range(start..=30))
+        };
+
+        (0..rng.gen_range(1..=100))
+            .map(|_| format!("{},{}\n", range(rng), range(rng)))
+            .collect()
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        pairs(input)
+            .iter()
+            .filter(|(first, second)| first.is_subset(second) || second.is_subset(first))
+            .count()
+            .into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        pairs(input)
+            .iter()
+            .filter(|(first, second)| !first.is_disjoint(second))
+            .count()
+            .into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day04>(2000);
+}
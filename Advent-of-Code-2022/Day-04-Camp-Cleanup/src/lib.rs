@@ -1,68 +1,47 @@
-pub fn process_part_1(input: &str) -> String {
-    let result: usize = input
-        .lines()
-        .map(|elf_pair| elf_pair.split_once(',').unwrap())
-        .filter(|(first, second)| {
-            let (first_start, first_end): (u32, u32) = first
-                .split_once('-')
-                .map(|(start, end)| (start.parse().unwrap(), end.parse().unwrap()))
-                .unwrap();
-
-            let (second_start, second_end): (u32, u32) = second
-                .split_once('-')
-                .map(|(start, end)| (start.parse().unwrap(), end.parse().unwrap()))
-                .unwrap();
-
-            (first_start >= second_start && first_end <= second_end) || (second_start >= first_start && second_end <= first_end)
-        })
-        .count();
-
-    return result.to_string();
-}
-
-pub fn process_part_2(input: &str) -> String {
-    let result: usize = input
-        .lines()
-        .map(|elf_pair| elf_pair.split_once(',').unwrap())
-        .filter(|(first, second)| {
-            let (first_start, first_end): (u32, u32) = first
-                .split_once('-')
-                .map(|(start, end)| (start.parse().unwrap(), end.parse().unwrap()))
-                .unwrap();
+#[cfg(test)]
+mod fuzz;
 
-            let (second_start, second_end): (u32, u32) = second
-                .split_once('-')
-                .map(|(start, end)| (start.parse().unwrap(), end.parse().unwrap()))
-                .unwrap();
+use aoc_core::parse::{finish, lines, IResult};
+use aoc_core::{Answer, ParseError, Solution};
+use nom::character::complete::{self, char};
+use nom::sequence::separated_pair;
 
-            first_start <= second_end && first_end >= second_start
-        })
-        .count();
+type Assignment = (u32, u32);
 
-    return result.to_string();
+fn assignment(input: &str) -> IResult<&str, Assignment> {
+    separated_pair(complete::u32, char('-'), complete::u32)(input)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day04;
 
-    use std::fs;
+impl Solution for Day04 {
+    type Parsed = Vec<(Assignment, Assignment)>;
 
-    #[test]
-    fn test_part_1() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        finish(input, lines(separated_pair(assignment, char(','), assignment)))
+    }
 
-        let result = process_part_1(&input);
+    fn part_1(elf_pairs: &Self::Parsed) -> Answer {
+        let result: usize = elf_pairs
+            .iter()
+            .filter(|((first_start, first_end), (second_start, second_end))| {
+                (first_start >= second_start && first_end <= second_end) || (second_start >= first_start && second_end <= first_end)
+            })
+            .count();
 
-        assert_eq!(result, "2");
+        return result.into();
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
+    fn part_2(elf_pairs: &Self::Parsed) -> Answer {
+        let result: usize = elf_pairs
+            .iter()
+            .filter(|((first_start, first_end), (second_start, second_end))| {
+                first_start <= second_end && first_end >= second_start
+            })
+            .count();
 
-        let result = process_part_2(&input);
-
-        assert_eq!(result, "4");
+        return result.into();
     }
 }
+
+aoc_core::examples!();
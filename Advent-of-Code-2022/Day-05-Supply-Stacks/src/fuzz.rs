@@ -0,0 +1,111 @@
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day05;
+
+fn drawing(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+
+    let mut drawing: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| stack.get(level).map_or("   ".to_string(), |krate| format!("[{krate}]")))
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect();
+
+    drawing.push((1..=stacks.len()).map(|i| format!(" {i} ")).collect::<Vec<String>>().join(" "));
+
+    drawing.join("\n")
+}
+
+// Reads the drawing column by column, then makes each move one crate (part 1) or one whole pile (part 2) at a time
+fn rearrange(input: &str, one_at_a_time: bool) -> Answer {
+    let (drawing, moves) = input.split_once("\n\n").unwrap();
+
+    let mut rows: Vec<&str> = drawing.lines().collect();
+    let labels = rows.pop().unwrap().split_whitespace().count();
+
+    let mut stacks: Vec<Vec<char>> = (0..labels)
+        .map(|i| {
+            rows.iter()
+                .rev()
+                .filter_map(|row| row.chars().nth(4 * i + 1))
+                .filter(|krate| krate.is_ascii_uppercase())
+                .collect()
+        })
+        .collect();
+
+    for line in moves.lines() {
+        let words: Vec<usize> = line.split(' ').filter_map(|word| word.parse().ok()).collect();
+        let (number, from, to) = (words[0], words[1] - 1, words[2] - 1);
+
+        if one_at_a_time {
+            for _ in 0..number {
+                let krate = stacks[from].pop().unwrap();
+
+                stacks[to].push(krate);
+            }
+        } else {
+            let rest = stacks[from].len() - number;
+            let pile = stacks[from].split_off(rest);
+
+            stacks[to].extend(pile);
+        }
+    }
+
+    stacks.iter().filter_map(|stack| stack.last()).collect::<String>().into()
+}
+
+impl Fuzz for Day05 {
+    fn generate(rng: &mut StdRng) -> String {
+        let mut stacks: Vec<Vec<char>> = (0..rng.gen_range(2..=9))
+            .map(|_| (0..rng.gen_range(0..=8)).map(|_| rng.gen_range('A'..='Z')).collect())
+            .collect();
+
+        if stacks.iter().all(Vec::is_empty) {
+            stacks[0].push('A');
+        }
+
+        let mut input = drawing(&stacks) + "\n\n";
+
+        // Track the stacks while generating so every move takes crates that are actually there
+        for _ in 0..rng.gen_range(1..=30) {
+            let from = loop {
+                let from = rng.gen_range(0..stacks.len());
+
+                if !stacks[from].is_empty() {break from}
+            };
+
+            let to = (from + rng.gen_range(1..stacks.len())) % stacks.len();
+            let number = rng.gen_range(1..=stacks[from].len());
+
+            let rest = stacks[from].len() - number;
+            let pile = stacks[from].split_off(rest);
+
+            stacks[to].extend(pile);
+
+            input += &format!("move {number} from {} to {}\n", from + 1, to + 1);
+        }
+
+        input
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        rearrange(input, true)
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        rearrange(input, false)
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day05>(2000);
+}
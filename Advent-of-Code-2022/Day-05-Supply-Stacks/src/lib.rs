@@ -1,12 +1,15 @@
-use nom::IResult;
+#[cfg(test)]
+mod fuzz;
+
 use nom::branch::alt;
-use nom::bytes::complete::tag;
-use nom::character::streaming::space1;
 use nom::sequence::{delimited, preceded};
-use nom::character::complete::{self, alpha1, newline, digit1, multispace1};
+use nom::character::complete::{self, alpha1, digit1, multispace1, space1};
 use nom::multi::{separated_list1, many1};
 
-fn krate(input: &str) -> IResult<&str, Option<&str>> {
+use aoc_core::parse::{eol, finish, lines, tag, Error, IResult};
+use aoc_core::{Answer, ParseError, Solution};
+
+fn krate(input: &str) -> IResult<&str, Option<char>> {
     let (input, char) = alt((
         tag("   "),
         delimited(
@@ -18,55 +21,79 @@ fn krate(input: &str) -> IResult<&str, Option<&str>> {
 
     let result = match char {
         "   " => None,
-        value => Some(value),
+        value => value.chars().next(),
     };
 
     Ok((input, result))
 }
 
-fn line(input: &str) -> IResult<&str, Vec<Option<&str>>> {
+fn line(input: &str) -> IResult<&str, Vec<Option<char>>> {
     let (input, result) = separated_list1(tag(" "), krate)(input)?;
 
     Ok((input, result))
 }
 
 #[derive(Debug)]
-struct Instruction {
-    number: u32,
-    from: u32,
-    to: u32,
+pub struct Instruction {
+    number: usize,
+    from: usize,
+    to: usize,
+}
+
+// Where each number of an instruction starts, so the checks that need the stacks can point back at it
+struct Positions<'a> {
+    number: &'a str,
+    from: &'a str,
+    to: &'a str,
 }
 
-fn instruction(input: &str) -> IResult<&str, Instruction> {
+fn instruction(input: &str) -> IResult<&str, ((u32, u32, u32), Positions<'_>)> {
     let (input, _) = tag("move ")(input)?;
+    let number_at = input;
     let (input, number) = complete::u32(input)?;
     let (input, _) = tag(" from ")(input)?;
+    let from_at = input;
     let (input, from) = complete::u32(input)?;
     let (input, _) = tag(" to ")(input)?;
+    let to_at = input;
     let (input, to) = complete::u32(input)?;
 
-    Ok((
-        input,
-        Instruction {
-            number,
-            from: from - 1,
-            to: to - 1
-        },
-    ))
+    Ok((input, ((number, from, to), Positions {number: number_at, from: from_at, to: to_at})))
+}
+
+#[derive(Debug)]
+pub struct Procedure {
+    crate_stacks: Vec<Vec<char>>,
+    instructions: Vec<Instruction>,
 }
 
-fn crates(input: &str) -> IResult<&str, (Vec<Vec<&str>>, Vec<Instruction>)> {
-    let (input, crates_horizontal) = separated_list1(newline, line)(input)?;
-    let (input, _) = newline(input)?;
-    let (input, _) = many1(preceded(space1, digit1))(input)?;
+fn invalid<T>(input: &str, expected: String) -> IResult<&str, T> {
+    Err(nom::Err::Failure(Error::expected(input, expected)))
+}
+
+fn crates(input: &str) -> IResult<&str, Procedure> {
+    let rows = input;
+
+    let (input, crates_horizontal) = separated_list1(eol, line)(input)?;
+    let (input, _) = eol(input)?;
+    let (input, labels) = many1(preceded(space1, digit1))(input)?;
     let (input, _) = multispace1(input)?;
-    let (input, instructions) = separated_list1(newline, instruction)(input)?;
-    
-    let mut crates_vertical: Vec<Vec<Option<&str>>> = vec![];
+    let (input, instructions) = lines(instruction)(input)?;
+
+    // Rows stop at their last crate once trailing whitespace is gone, so the labels are what say how many stacks there are
+    let stacks = labels.len();
 
-    (0..=crates_horizontal.len()).for_each(|_| {
-            crates_vertical.push(vec![]);
-    });
+    let mut line_start = 0;
+
+    for (row, line) in crates_horizontal.iter().zip(rows.split('\n')) {
+        if row.len() > stacks {
+            return invalid(&rows[line_start + 4 * stacks..], format!("at most {stacks} crates in a row, one per stack"));
+        }
+
+        line_start += line.len() + 1;
+    }
+
+    let mut crates_vertical: Vec<Vec<Option<char>>> = vec![vec![]; stacks];
 
     crates_horizontal
         .iter()
@@ -76,11 +103,11 @@ fn crates(input: &str) -> IResult<&str, (Vec<Vec<&str>>, Vec<Instruction>)> {
                 .iter()
                 .enumerate()
                 .for_each(|(i, krate)| {
-                    crates_vertical[i].push(krate.clone());
+                    crates_vertical[i].push(*krate);
                 });
         });
 
-    let crate_stacks: Vec<Vec<&str>> = crates_vertical
+    let crate_stacks: Vec<Vec<char>> = crates_vertical
         .iter()
         .map(|stack| {
             stack
@@ -90,83 +117,143 @@ fn crates(input: &str) -> IResult<&str, (Vec<Vec<&str>>, Vec<Instruction>)> {
         })
         .collect();
 
-    Ok((input, (crate_stacks, instructions)))
-}
+    // Follow how tall each stack gets, so no instruction can move more crates than its stack holds at the time
+    let mut heights: Vec<usize> = crate_stacks.iter().map(|stack| stack.len()).collect();
 
-pub fn process_part_1(input: &str) -> String {
-    let (_, (mut crate_stacks, instructions)) = crates(input).unwrap();
+    let mut checked = vec![];
 
-    instructions
-        .iter()
-        .for_each(|Instruction {number, from, to}| {
-            let len = crate_stacks[*from as usize].len();
-            crate_stacks[*from as usize].drain((len - *number as usize)..)
-                .rev()
-                .collect::<Vec<&str>>()
-                .iter()
-                .for_each(|krate| {
-                    crate_stacks[*to as usize].push(krate);
-                })
-        });
+    for ((number, from, to), positions) in instructions {
+        for (stack, position) in [(from, positions.from), (to, positions.to)] {
+            if stack == 0 || stack as usize > stacks {
+                return invalid(position, format!("a stack from 1 to {stacks}"));
+            }
+        }
 
-    let result: String = crate_stacks
-        .iter()
-        .map(|stack| match stack.last() {
-            Some(c) => c,
-            None => "",
-        })
-        .collect();
+        let (number, from, to) = (number as usize, from as usize - 1, to as usize - 1);
+
+        if number > heights[from] {
+            return invalid(positions.number, format!("at most {} crates, as many as stack {} holds", heights[from], from + 1));
+        }
 
-    return result.to_string();
+        heights[from] -= number;
+        heights[to] += number;
+
+        checked.push(Instruction {number, from, to});
+    }
+
+    Ok((input, Procedure {crate_stacks, instructions: checked}))
 }
 
-pub fn process_part_2(input: &str) -> String {
-    let (_, (mut crate_stacks, instructions)) = crates(input).unwrap();
+pub struct Day05;
 
-    instructions
-        .iter()
-        .for_each(|Instruction {number, from, to}| {
-            let len = crate_stacks[*from as usize].len();
-            crate_stacks[*from as usize].drain((len - *number as usize)..)
-                .collect::<Vec<&str>>()
-                .iter()
-                .for_each(|krate| {
-                    crate_stacks[*to as usize].push(krate);
-                })
-        });
+impl Solution for Day05 {
+    type Parsed = Procedure;
 
-    let result: String = crate_stacks
-        .iter()
-        .map(|stack| match stack.last() {
-            Some(c) => c,
-            None => "",
-        })
-        .collect();
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        finish(input, crates)
+    }
+
+    fn part_1(procedure: &Self::Parsed) -> Answer {
+        let mut crate_stacks = procedure.crate_stacks.clone();
+
+        procedure.instructions
+            .iter()
+            .for_each(|Instruction {number, from, to}| {
+                let len = crate_stacks[*from].len();
+                crate_stacks[*from].drain((len - *number)..)
+                    .rev()
+                    .collect::<Vec<char>>()
+                    .iter()
+                    .for_each(|krate| {
+                        crate_stacks[*to].push(*krate);
+                    })
+            });
 
-    return result.to_string();
+        let result: String = crate_stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect();
+
+        return result.into();
+    }
+
+    fn part_2(procedure: &Self::Parsed) -> Answer {
+        let mut crate_stacks = procedure.crate_stacks.clone();
+
+        procedure.instructions
+            .iter()
+            .for_each(|Instruction {number, from, to}| {
+                let len = crate_stacks[*from].len();
+                crate_stacks[*from].drain((len - *number)..)
+                    .collect::<Vec<char>>()
+                    .iter()
+                    .for_each(|krate| {
+                        crate_stacks[*to].push(*krate);
+                    })
+            });
+
+        let result: String = crate_stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect();
+
+        return result.into();
+    }
 }
 
+aoc_core::examples!();
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use std::fs;
+    const INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("move 2 from 2 to 1", "move 2 form 2 to 1");
+
+        let error = Day05::parse(&input).unwrap_err();
+
+        assert_eq!((error.line(), error.column()), (8, 7));
+        assert_eq!(error.expected(), "\" from \"");
+    }
+
+    #[test]
+    fn test_more_stacks_than_rows() {
+        let procedure = Day05::parse("[A] [B] [C]\n 1   2   3 \n\nmove 1 from 3 to 1\n").unwrap();
+
+        assert_eq!(Day05::part_1(&procedure), Answer::from("CB"));
+        assert_eq!(Day05::part_2(&procedure), Answer::from("CB"));
+    }
 
     #[test]
-    fn test_part_1() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
+    fn test_stack_out_of_range() {
+        let error = Day05::parse(&INPUT.replace("move 1 from 2 to 1", "move 1 from 0 to 1")).unwrap_err();
+
+        assert_eq!((error.line(), error.column()), (6, 13));
+        assert_eq!(error.expected(), "a stack from 1 to 3");
 
-        let result = process_part_1(&input);
+        let error = Day05::parse(&INPUT.replace("move 3 from 1 to 3", "move 3 from 1 to 4")).unwrap_err();
 
-        assert_eq!(result, "CMZ");
+        assert_eq!((error.line(), error.column()), (7, 18));
+        assert_eq!(error.expected(), "a stack from 1 to 3");
     }
 
     #[test]
-    fn test_part_2() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
+    fn test_moving_too_many() {
+        // The first move leaves three crates on stack 1
+        let error = Day05::parse(&INPUT.replace("move 3 from 1 to 3", "move 4 from 1 to 3")).unwrap_err();
 
-        let result = process_part_2(&input);
+        assert_eq!((error.line(), error.column()), (7, 6));
+        assert_eq!(error.expected(), "at most 3 crates, as many as stack 1 holds");
+    }
+
+    #[test]
+    fn test_row_wider_than_labels() {
+        let error = Day05::parse("[A] [B]\n[C] [D] [E]\n 1   2\n\nmove 1 from 1 to 2\n").unwrap_err();
 
-        assert_eq!(result, "MCD");
+        assert_eq!((error.line(), error.column()), (2, 9));
+        assert_eq!(error.expected(), "at most 2 crates in a row, one per stack");
     }
 }
@@ -0,0 +1,52 @@
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::seq::SliceRandom;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day06;
+
+// Compares every pair of characters in every window until one has no repeats
+fn marker(signal: &str, length: usize) -> Answer {
+    let signal: Vec<char> = signal.trim().chars().collect();
+
+    (length..=signal.len())
+        .find(|&end| {
+            let window = &signal[end - length..end];
+
+            (0..length).all(|i| (i + 1..length).all(|j| window[i] != window[j]))
+        })
+        .unwrap()
+        .into()
+}
+
+impl Fuzz for Day06 {
+    fn generate(rng: &mut StdRng) -> String {
+        // A small alphabet keeps repeats common, and a run of 14 different letters guarantees both markers exist
+        let alphabet = rng.gen_range(4..=26);
+
+        let letter = |rng: &mut StdRng| (b'a' + rng.gen_range(0..alphabet)) as char;
+
+        let mut distinct: Vec<char> = ('a'..='z').collect();
+
+        distinct.shuffle(rng);
+
+        let prefix: String = (0..rng.gen_range(0..=100)).map(|_| letter(rng)).collect();
+        let suffix: String = (0..rng.gen_range(0..=20)).map(|_| letter(rng)).collect();
+
+        format!("{prefix}{}{suffix}\n", distinct[..14].iter().collect::<String>())
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        marker(input, 4)
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        marker(input, 14)
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day06>(2000);
+}
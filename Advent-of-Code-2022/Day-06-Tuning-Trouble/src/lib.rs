@@ -1,66 +1,59 @@
+#[cfg(test)]
+mod fuzz;
+
 use std::collections::BTreeSet;
 
-pub fn process_part_1(input: &str) -> String {
-    let sequence_len = 4;
+use aoc_core::parse::finish;
+use aoc_core::{Answer, ParseError, Solution};
+use nom::character::complete::alpha1;
+use nom::Parser;
 
-    let result = input
-        .chars()
-        .collect::<Vec<char>>()
-        .windows(sequence_len)
-        .enumerate()
-        .find(|(_, subsequence)| {
-            let unique_chars: BTreeSet<&char> = subsequence
-                .iter()
-                .collect();
+pub struct Day06;
 
-            subsequence.len() == unique_chars.len()
-        })
-        .map(|(i, _)| i + sequence_len)
-        .unwrap();
+impl Solution for Day06 {
+    type Parsed = Vec<char>;
 
-    return result.to_string();
-}
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        finish(input, alpha1.map(|signal: &str| signal.chars().collect()))
+    }
 
-pub fn process_part_2(input: &str) -> String {
-    let sequence_len = 14;
+    fn part_1(signal: &Self::Parsed) -> Answer {
+        let sequence_len = 4;
 
-    let result = input
-        .chars()
-        .collect::<Vec<char>>()
-        .windows(sequence_len)
-        .enumerate()
-        .find(|(_, subsequence)| {
-            let unique_chars: BTreeSet<&char> = subsequence
-                .iter()
-                .collect();
+        let result = signal
+            .windows(sequence_len)
+            .enumerate()
+            .find(|(_, subsequence)| {
+                let unique_chars: BTreeSet<&char> = subsequence
+                    .iter()
+                    .collect();
 
-            subsequence.len() == unique_chars.len()
-        })
-        .map(|(i, _)| i + sequence_len)
-        .unwrap();
+                subsequence.len() == unique_chars.len()
+            })
+            .map(|(i, _)| i + sequence_len)
+            .unwrap();
 
-    return result.to_string();
-}
+        return result.into();
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn part_2(signal: &Self::Parsed) -> Answer {
+        let sequence_len = 14;
 
-    #[test]
-    fn test_part_1() {
-        assert_eq!(process_part_1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), "7");
-        assert_eq!(process_part_1("bvwbjplbgvbhsrlpgdmjqwftvncz"), "5");
-        assert_eq!(process_part_1("nppdvjthqldpwncqszvftbrmjlhg"), "6");
-        assert_eq!(process_part_1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), "10");
-        assert_eq!(process_part_1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), "11");
-    }
+        let result = signal
+            .windows(sequence_len)
+            .enumerate()
+            .find(|(_, subsequence)| {
+                let unique_chars: BTreeSet<&char> = subsequence
+                    .iter()
+                    .collect();
 
-    #[test]
-    fn test_part_2() {
-        assert_eq!(process_part_2("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), "19");
-        assert_eq!(process_part_2("bvwbjplbgvbhsrlpgdmjqwftvncz"), "23");
-        assert_eq!(process_part_2("nppdvjthqldpwncqszvftbrmjlhg"), "23");
-        assert_eq!(process_part_2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), "29");
-        assert_eq!(process_part_2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), "26");
+                subsequence.len() == unique_chars.len()
+            })
+            .map(|(i, _)| i + sequence_len)
+            .unwrap();
+
+        return result.into();
     }
 }
+
+aoc_core::examples!();
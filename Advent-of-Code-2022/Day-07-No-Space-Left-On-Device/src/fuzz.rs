@@ -0,0 +1,159 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::seq::SliceRandom;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day07;
+
+const DISK: u32 = 70_000_000;
+const NEEDED: u32 = 30_000_000;
+
+#[derive(Default)]
+struct Directory {
+    files: Vec<(String, u32)>,
+    directories: Vec<(String, Directory)>,
+}
+
+impl Directory {
+    fn size(&self) -> u32 {
+        self.files.iter().map(|(_, size)| size).sum::<u32>() + self.directories.iter().map(|(_, directory)| directory.size()).sum::<u32>()
+    }
+
+    fn generate(rng: &mut StdRng, depth: usize) -> Self {
+        let mut directory = Directory::default();
+
+        let mut names = BTreeSet::new();
+        let mut name = |rng: &mut StdRng| loop {
+            let name: String = (0..rng.gen_range(1..=3)).map(|_| rng.gen_range('a'..='e')).collect();
+
+            if names.insert(name.clone()) {break name}
+        };
+
+        if depth < 4 {
+            for _ in 0..rng.gen_range(0..=3) {
+                directory.directories.push((name(rng), Directory::generate(rng, depth + 1)));
+            }
+        }
+
+        // `ls` always lists something, so every directory holds at least one file or directory
+        let files = rng.gen_range(usize::from(directory.directories.is_empty())..=4);
+
+        for _ in 0..files {
+            let name = if rng.gen_bool(0.5) {format!("{}.{}", name(rng), name(rng))} else {name(rng)};
+            let size = if rng.gen_bool(0.98) {rng.gen_range(1..=60_000)} else {rng.gen_range(1_000_000..=4_000_000)};
+
+            directory.files.push((name, size));
+        }
+
+        directory
+    }
+
+    fn transcript(&self, rng: &mut StdRng, output: &mut String) {
+        let mut listing: Vec<String> = self.directories
+            .iter()
+            .map(|(name, _)| format!("dir {name}"))
+            .chain(self.files.iter().map(|(name, size)| format!("{size} {name}")))
+            .collect();
+
+        listing.shuffle(rng);
+
+        *output += "$ ls\n";
+        *output += &(listing.join("\n") + "\n");
+
+        self.directories.iter().for_each(|(name, directory)| {
+            *output += &format!("$ cd {name}\n");
+
+            directory.transcript(rng, output);
+
+            *output += "$ cd ..\n";
+        });
+    }
+}
+
+// Replays the transcript into a list of every file by its full path, then sizes each directory by adding up the files under it
+fn directory_sizes(input: &str) -> Vec<u32> {
+    let mut path: Vec<&str> = vec![];
+    let mut directories = BTreeSet::new();
+    let mut files = BTreeMap::new();
+
+    for line in input.lines() {
+        let words: Vec<&str> = line.split(' ').collect();
+
+        match words[..] {
+            ["$", "cd", "/"] => path.clear(),
+            ["$", "cd", ".."] => {
+                path.pop();
+            },
+            ["$", "cd", name] => path.push(name),
+            ["$", "ls"] | ["dir", _] => {},
+            [size, name] => {
+                files.insert([&path[..], &[name]].concat(), size.parse::<u32>().unwrap());
+            },
+            _ => unreachable!(),
+        }
+
+        directories.insert(path.clone());
+    }
+
+    directories
+        .iter()
+        .map(|directory| {
+            files
+                .iter()
+                .filter(|(file, _)| file.starts_with(directory))
+                .map(|(_, size)| size)
+                .sum()
+        })
+        .collect()
+}
+
+impl Fuzz for Day07 {
+    fn generate(rng: &mut StdRng) -> String {
+        // The filesystem needs to be full enough that something has to be deleted, without being fuller than the disk
+        let used = rng.gen_range(DISK - NEEDED + 1..=DISK);
+
+        let mut root = loop {
+            let root = Directory::generate(rng, 0);
+
+            if root.size() <= used {break root}
+        };
+
+        root.files.push(("padding".to_string(), used - root.size()));
+
+        let mut transcript = "$ cd /\n".to_string();
+
+        root.transcript(rng, &mut transcript);
+
+        transcript
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        directory_sizes(input)
+            .into_iter()
+            .filter(|&size| size <= 100_000)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        let sizes = directory_sizes(input);
+
+        let used = sizes.iter().max().unwrap();
+        let to_free = NEEDED - (DISK - used);
+
+        sizes
+            .into_iter()
+            .filter(|&size| size >= to_free)
+            .min()
+            .unwrap()
+            .into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day07>(2000);
+}
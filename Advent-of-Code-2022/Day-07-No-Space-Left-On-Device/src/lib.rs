@@ -1,35 +1,36 @@
+#[cfg(test)]
+mod fuzz;
+
 use std::collections::BTreeMap;
 
 use nom::{
-    IResult,
-    bytes::complete::{
-        tag,
-        is_a
-    },
+    bytes::complete::is_a,
     branch::alt,
     character::complete::{
         alpha1,
-        self,
-        newline
+        self
     },
     sequence::separated_pair,
     multi::separated_list1
 };
 
-enum Command<'a> {
-    Cd(Cd<'a>),
-    Ls(Vec<Files<'a>>),
+use aoc_core::parse::{eol, finish, lines, tag, IResult};
+use aoc_core::{Answer, ParseError, Solution};
+
+pub enum Command {
+    Cd(Cd),
+    Ls(Vec<Files>),
 }
 
-enum Cd<'a> {
+pub enum Cd {
     Root,
     Up,
-    Down(&'a str),
+    Down(String),
 }
 
-enum Files<'a> {
+pub enum Files {
     File(u32),
-    Dir(&'a str),
+    Dir,
 }
 
 fn cd(input: &str) -> IResult<&str, Command> {
@@ -39,7 +40,7 @@ fn cd(input: &str) -> IResult<&str, Command> {
     let cmd = match dir {
         "/" => Command::Cd(Cd::Root),
         ".." => Command::Cd(Cd::Up),
-        name => Command::Cd(Cd::Down(name)),
+        name => Command::Cd(Cd::Down(name.to_string())),
     };
 
     Ok((input, cmd))
@@ -53,21 +54,21 @@ fn file(input: &str) -> IResult<&str, Files> {
 
 fn dir(input: &str) -> IResult<&str, Files> {
     let (input, _) = tag("dir ")(input)?;
-    let (input, name) = alpha1(input)?;
+    let (input, _) = alpha1(input)?;
 
-    Ok((input, Files::Dir(name)))
+    Ok((input, Files::Dir))
 }
 
 fn ls(input: &str) -> IResult<&str, Command> {
     let (input, _) = tag("$ ls")(input)?;
-    let (input, _) = newline(input)?;
-    let (input, files) = separated_list1(newline, alt((file, dir)))(input)?;
+    let (input, _) = eol(input)?;
+    let (input, files) = separated_list1(eol, alt((file, dir)))(input)?;
 
     Ok((input, Command::Ls(files)))
 }
 
 fn commands(input: &str) -> IResult<&str, Vec<Command>> {
-    let (input, cmd) = separated_list1(newline, alt((cd, ls)))(input)?;
+    let (input, cmd) = lines(alt((cd, ls)))(input)?;
 
     Ok((input, cmd))
 }
@@ -101,66 +102,49 @@ fn calculate_sizes<'a>((mut context, mut sizes): (Vec<&'a str>, BTreeMap<Vec<&'a
     (context, sizes)
 }
 
-pub fn process_part_1(input: &str) -> String {
-    let commands = commands(input).unwrap().1;
-
-    let (_, sizes) = commands
-        .iter()
-        .fold((vec![], BTreeMap::new()), calculate_sizes);
-
-    let result: u32 = sizes
-        .iter()
-        .filter(|(_, &size)| size < 100_000)
-        .map(|(_, size)| size)
-        .sum();
-
-    return result.to_string();
-}
-
-pub fn process_part_2(input: &str) -> String {
-    let commands = commands(input).unwrap().1;
-
-    let (_, sizes) = commands
-        .iter()
-        .fold((vec![], BTreeMap::new()), calculate_sizes);
+pub struct Day07;
 
-    let total_space = 70_000_000;
-    let needed_space = 30_000_000;
-    let used_space = sizes.get(&vec![""]).unwrap();
-    let free_space = total_space - used_space;
-    let free_space_needed = needed_space - free_space;
+impl Solution for Day07 {
+    type Parsed = Vec<Command>;
 
-    let result: u32 = sizes
-        .iter()
-        .filter(|(_, &size)| size > free_space_needed)
-        .map(|(_, size)| *size)
-        .min()
-        .unwrap();
-
-    return result.to_string();
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    use std::fs;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        finish(input, commands)
+    }
 
-    #[test]
-    fn test_part_1() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
+    fn part_1(commands: &Self::Parsed) -> Answer {
+        let (_, sizes) = commands
+            .iter()
+            .fold((vec![], BTreeMap::new()), calculate_sizes);
 
-        let result = process_part_1(&input);
+        let result: u32 = sizes
+            .iter()
+            .filter(|(_, &size)| size < 100_000)
+            .map(|(_, size)| size)
+            .sum();
 
-        assert_eq!(result, "95437");
+        return result.into();
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
-
-        let result = process_part_2(&input);
-
-        assert_eq!(result, "24933642");
+    fn part_2(commands: &Self::Parsed) -> Answer {
+        let (_, sizes) = commands
+            .iter()
+            .fold((vec![], BTreeMap::new()), calculate_sizes);
+
+        let total_space = 70_000_000;
+        let needed_space = 30_000_000;
+        let used_space = sizes.get(&vec![""]).unwrap();
+        let free_space = total_space - used_space;
+        let free_space_needed = needed_space - free_space;
+
+        let result: u32 = sizes
+            .iter()
+            .filter(|(_, &size)| size > free_space_needed)
+            .map(|(_, size)| *size)
+            .min()
+            .unwrap();
+
+        return result.into();
     }
 }
+
+aoc_core::examples!();
@@ -0,0 +1,91 @@
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day08;
+
+const DIRECTIONS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+fn heights(input: &str) -> Vec<Vec<u32>> {
+    input
+        .lines()
+        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect()
+}
+
+// Heights of the trees walking from (x, y) towards the edge, nearest first
+fn line_of_sight(trees: &[Vec<u32>], x: usize, y: usize, (dx, dy): (isize, isize)) -> Vec<u32> {
+    let mut line = vec![];
+
+    let (mut x, mut y) = (x as isize + dx, y as isize + dy);
+
+    while y >= 0 && y < trees.len() as isize && x >= 0 && x < trees[0].len() as isize {
+        line.push(trees[y as usize][x as usize]);
+
+        x += dx;
+        y += dy;
+    }
+
+    line
+}
+
+impl Fuzz for Day08 {
+    fn generate(rng: &mut StdRng) -> String {
+        let (width, height) = (rng.gen_range(1..=12), rng.gen_range(1..=12));
+
+        // A narrow range of heights makes ties, which is where visibility gets interesting
+        let tallest = rng.gen_range(1..=9);
+
+        (0..height)
+            .map(|_| (0..width).map(|_| char::from_digit(rng.gen_range(0..=tallest), 10).unwrap()).collect::<String>() + "\n")
+            .collect()
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        let trees = heights(input);
+
+        let mut visible = 0;
+
+        for y in 0..trees.len() {
+            for x in 0..trees[y].len() {
+                if DIRECTIONS.iter().any(|&direction| line_of_sight(&trees, x, y, direction).iter().all(|&other| other < trees[y][x])) {
+                    visible += 1;
+                }
+            }
+        }
+
+        visible.into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        let trees = heights(input);
+
+        let mut best = 0;
+
+        for y in 0..trees.len() {
+            for x in 0..trees[y].len() {
+                let score: usize = DIRECTIONS
+                    .iter()
+                    .map(|&direction| {
+                        let line = line_of_sight(&trees, x, y, direction);
+
+                        match line.iter().position(|&other| other >= trees[y][x]) {
+                            Some(blocker) => blocker + 1,
+                            None => line.len(),
+                        }
+                    })
+                    .product();
+
+                best = best.max(score);
+            }
+        }
+
+        best.into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day08>(2000);
+}
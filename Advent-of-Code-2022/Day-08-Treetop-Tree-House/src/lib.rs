@@ -1,176 +1,61 @@
-pub fn process_part_1(input: &str) -> String {
-    let trees: Vec<Vec<u32>> = input
-        .lines()
-        .map(|line| {
-            line
-                .chars()
-                .map(|c| c.to_string().parse::<u32>().unwrap())
-                .collect()
-        })
-        .collect();
-
-    let rows = trees.len();
-    let columns = trees[0].len();
-
-    let mut visible_trees: Vec<Vec<bool>> = trees
-        .iter()
-        .enumerate()
-        .map(|(r, tree_row)| {
-            tree_row
-                .iter()
-                .enumerate()
-                .map(|(c, _)| if r == 0 || r == rows - 1 || c == 0 || c == columns - 1 {true} else {false})
-                .collect()
-        })
-        .collect();
-
-    let mut current_tree_height = 0;
-
-    // North
-    (0..columns).for_each(|c| {
-        current_tree_height = 0;
-
-        (0..rows).for_each(|r| {
-            if r == 0 {
-                current_tree_height = trees[r][c];
-            } else if trees[r][c] > current_tree_height {
-                current_tree_height = trees[r][c];
-                visible_trees[r][c] = true;
-            }
-        });
-    });
-
-    // West
-    (0..rows).for_each(|r| {
-        current_tree_height = 0;
-
-        (0..columns).for_each(|c| {
-            if c == 0 {
-                current_tree_height = trees[r][c];
-            } else if trees[r][c] > current_tree_height {
-                current_tree_height = trees[r][c];
-                visible_trees[r][c] = true;
-            }
-        });
-    });
-
-    // South
-    (0..columns).rev().for_each(|c| {
-        current_tree_height = 0;
-
-        (0..rows).rev().for_each(|r| {
-            if r == rows - 1 {
-                current_tree_height = trees[r][c];
-            } else if trees[r][c] > current_tree_height {
-                current_tree_height = trees[r][c];
-                visible_trees[r][c] = true;
-            }
-        });
-    });
-
-    // East
-    (0..rows).rev().for_each(|r| {
-        current_tree_height = 0;
+#[cfg(test)]
+mod fuzz;
+mod visual;
 
-        (0..columns).rev().for_each(|c| {
-            if c == columns - 1 {
-                current_tree_height = trees[r][c];
-            } else if trees[r][c] > current_tree_height {
-                current_tree_height = trees[r][c];
-                visible_trees[r][c] = true;
-            }
-        });
-    });
+use aoc_core::{Answer, ParseError, Solution};
+use aoc_grid::{Direction, Grid, Position};
 
-    let result: usize = visible_trees
+// A tree is visible if every tree between it and one of the edges is shorter
+fn visible(trees: &Grid<u32>, position: Position) -> bool {
+    Direction::ALL
         .iter()
-        .flatten()
-        .filter(|&&visible| visible)
-        .count();
-
-    return result.to_string();
+        .any(|&direction| trees.ray(position, direction).all(|other| trees[other] < trees[position]))
 }
 
-pub fn process_part_2(input: &str) -> String {
-    let trees: Vec<Vec<u32>> = input
-        .lines()
-        .map(|line| {
-            line
-                .chars()
-                .map(|c| c.to_string().parse::<u32>().unwrap())
-                .collect()
-        })
-        .collect();
-
-    let rows = trees.len();
-    let columns = trees[0].len();
-
-    let mut result = 0;
-
-    trees.iter().enumerate().for_each(|(r, tree_row)| {
-        tree_row.iter().enumerate().for_each(|(c, &tree_height)| {
-            let mut scores = [0, 0, 0, 0];
-
-            // North
-            for i in (0..r).rev() {
-                scores[0] += 1;
-
-                if tree_height <= trees[i][c] {break}
-            }
-
-            // West
-            for i in (0..c).rev() {
-                scores[1] += 1;
-
-                if tree_height <= trees[r][i] {break}
-            }
-
-            // South
-            for i in (r + 1)..rows {
-                scores[2] += 1;
-
-                if tree_height <= trees[i][c] {break}
-            }
-
-            // East
-            for i in (c + 1)..columns {
-                scores[3] += 1;
-
-                if tree_height <= trees[r][i] {break}
-            }
+fn scenic_score(trees: &Grid<u32>, position: Position) -> usize {
+    Direction::ALL
+        .iter()
+        .map(|&direction| {
+            let mut score = 0;
 
-            let scenic_score = scores.iter().product();
+            for other in trees.ray(position, direction) {
+                score += 1;
 
-            if scenic_score > result {
-                result = scenic_score;
+                if trees[position] <= trees[other] {break}
             }
-        });
-    });
 
-    return result.to_string();
+            score
+        })
+        .product()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day08;
 
-    use std::fs;
+impl Solution for Day08 {
+    type Parsed = Grid<u32>;
 
-    #[test]
-    fn test_part_1() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
 
-        let result = process_part_1(&input);
+    fn part_1(trees: &Self::Parsed) -> Answer {
+        let result = trees
+            .positions()
+            .filter(|&position| visible(trees, position))
+            .count();
 
-        assert_eq!(result, "21");
+        return result.into();
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
+    fn part_2(trees: &Self::Parsed) -> Answer {
+        let result = trees
+            .positions()
+            .map(|position| scenic_score(trees, position))
+            .max()
+            .unwrap();
 
-        let result = process_part_2(&input);
-
-        assert_eq!(result, "8");
+        return result.into();
     }
 }
+
+aoc_core::examples!();
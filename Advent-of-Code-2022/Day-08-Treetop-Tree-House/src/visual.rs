@@ -0,0 +1,64 @@
+use aoc_grid::Position;
+use aoc_visual::{Color, Frame, Recorder, Visualize};
+
+use crate::{scenic_score, visible, Day08};
+
+const UNSEEN: Color = Color::rgb(40, 40, 40);
+const HIDDEN: Color = Color::rgb(10, 60, 20);
+const VISIBLE: Color = Color::rgb(120, 230, 60);
+const SCENIC: Color = Color::rgb(255, 220, 80);
+
+fn shade(height: u32, color: Color) -> Color {
+    Color::BLACK.lerp(color, 0.4 + height as f64 / 15.0)
+}
+
+// Sweeps the forest a row at a time, colouring each tree by whether it can be seen from outside (part 1) or by its
+// scenic score (part 2), with taller trees drawn brighter
+impl Visualize for Day08 {
+    fn visualize(trees: &Self::Parsed, part: u8, recorder: &mut dyn Recorder) {
+        let best = trees.positions().map(|position| scenic_score(trees, position)).max().unwrap() as f64;
+
+        let mut frame: Frame = trees.map(|&height| shade(height, UNSEEN));
+
+        recorder.record(&frame);
+
+        (0..trees.height()).for_each(|y| {
+            (0..trees.width()).for_each(|x| {
+                let position = Position::new(x, y);
+
+                frame[position] = match part {
+                    1 => shade(trees[position], if visible(trees, position) {VISIBLE} else {HIDDEN}),
+                    _ => Color::BLACK.lerp(SCENIC, (scenic_score(trees, position) as f64).ln_1p() / best.ln_1p()),
+                };
+            });
+
+            recorder.record(&frame);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_visualize() {
+        let trees = Day08::parse_input(include_str!("../test_input.txt")).unwrap();
+
+        let mut frames = vec![];
+
+        Day08::visualize(&trees, 1, &mut frames);
+
+        let visible = frames
+            .last()
+            .unwrap()
+            .iter()
+            .filter(|&(position, &color)| color == shade(trees[position], VISIBLE))
+            .count();
+
+        assert_eq!(frames.len(), trees.height() + 1);
+        assert_eq!(visible, 21);
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::seq::SliceRandom;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day09;
+
+const DIAGONALS: [(i32, i32); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+fn touching(a: (i32, i32), b: (i32, i32)) -> bool {
+    (a.0 - b.0).abs() <= 1 && (a.1 - b.1).abs() <= 1
+}
+
+// The rules as the puzzle states them: a knot two steps straight up, down, left or right of the one behind pulls it one
+// step that way, and any other knot out of reach pulls it one step along whichever diagonal brings them back together
+fn follow(ahead: (i32, i32), behind: (i32, i32)) -> (i32, i32) {
+    if touching(ahead, behind) {
+        return behind;
+    }
+
+    match (ahead.0 - behind.0, ahead.1 - behind.1) {
+        (0, 2) => (behind.0, behind.1 + 1),
+        (0, -2) => (behind.0, behind.1 - 1),
+        (2, 0) => (behind.0 + 1, behind.1),
+        (-2, 0) => (behind.0 - 1, behind.1),
+        _ => DIAGONALS
+            .iter()
+            .map(|(dx, dy)| (behind.0 + dx, behind.1 + dy))
+            .find(|&moved| touching(ahead, moved))
+            .expect("No diagonal step brings the knots back together"),
+    }
+}
+
+fn tail_visits(input: &str, knots: usize) -> Answer {
+    let mut rope = vec![(0i32, 0i32); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+
+    for line in input.lines() {
+        let (direction, steps) = line.split_once(' ').unwrap();
+
+        let (dx, dy) = match direction {
+            "U" => (0, -1),
+            "R" => (1, 0),
+            "D" => (0, 1),
+            "L" => (-1, 0),
+            _ => unreachable!(),
+        };
+
+        for _ in 0..steps.parse().unwrap() {
+            rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+
+            for i in 1..knots {
+                rope[i] = follow(rope[i - 1], rope[i]);
+            }
+
+            visited.insert(rope[knots - 1]);
+        }
+    }
+
+    visited.len().into()
+}
+
+impl Fuzz for Day09 {
+    fn generate(rng: &mut StdRng) -> String {
+        (0..rng.gen_range(1..=60))
+            .map(|_| format!("{} {}\n", ["U", "R", "D", "L"].choose(rng).unwrap(), rng.gen_range(1..=12)))
+            .collect()
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        tail_visits(input, 2)
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        tail_visits(input, 10)
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day09>(2000);
+}
@@ -0,0 +1,64 @@
+use aoc_grid::{BoundingBox, Point, Position};
+use aoc_visual::{Color, Frame, Recorder, Visualize};
+
+use crate::{tail_positions, Day09};
+
+const VISITED: Color = Color::rgb(60, 90, 160);
+const HEAD: Color = Color::rgb(255, 60, 40);
+const TAIL: Color = Color::rgb(255, 230, 80);
+
+// A frame per step of the head, with the cells the tail has been to behind the rope and the knots fading from the
+// head's colour to the tail's
+impl Visualize for Day09 {
+    fn visualize(moves: &Self::Parsed, part: u8, recorder: &mut dyn Recorder) {
+        let knots = if part == 1 {2} else {10};
+
+        // Every knot stays within the area the head covers, so the head's path is enough to size the frames
+        let mut head = Point::<i32>::default();
+        let mut bounds = BoundingBox::new(head);
+
+        moves.iter().for_each(|&head_move| {
+            head += head_move;
+            bounds.include(head);
+        });
+
+        let cell = |point: Point<i32>| Position::new((point.x - bounds.min.x) as usize, (point.y - bounds.min.y) as usize);
+
+        // The cells the tail has visited only ever grow, so they are kept apart from the rope drawn over them
+        let mut trail = Frame::filled(bounds.width() as usize, bounds.height() as usize, Color::BLACK);
+
+        tail_positions(moves, knots, |rope| {
+            trail[cell(*rope.last().unwrap())] = VISITED;
+
+            let mut frame = trail.clone();
+
+            rope.iter().enumerate().rev().for_each(|(i, &knot)| {
+                frame[cell(knot)] = HEAD.lerp(TAIL, i as f64 / (knots - 1) as f64);
+            });
+
+            recorder.record(&frame);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_visualize() {
+        let moves = Day09::parse_input(include_str!("../test_input_1.txt")).unwrap();
+
+        let mut frames = vec![];
+
+        Day09::visualize(&moves, 1, &mut frames);
+
+        let last = frames.last().unwrap();
+
+        // Both knots finish on cells the tail has already been to
+        assert_eq!(frames.len(), moves.len());
+        assert_eq!(last.iter().filter(|(_, &color)| color != Color::BLACK).count(), 13);
+    }
+}
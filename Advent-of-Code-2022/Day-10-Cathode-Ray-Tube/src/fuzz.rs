@@ -0,0 +1,65 @@
+use aoc_core::{Answer, Picture};
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day10;
+
+// The value of X during every cycle, in order
+fn register(input: &str) -> Vec<i32> {
+    let mut x = 1;
+    let mut during = vec![];
+
+    for line in input.lines() {
+        match line.split_once(' ') {
+            Some(("addx", value)) => {
+                during.push(x);
+                during.push(x);
+
+                x += value.parse::<i32>().unwrap();
+            },
+            None => during.push(x),
+            _ => unreachable!(),
+        }
+    }
+
+    during
+}
+
+impl Fuzz for Day10 {
+    fn generate(rng: &mut StdRng) -> String {
+        (0..rng.gen_range(1..=200))
+            .map(|_| if rng.gen_bool(0.4) {"noop\n".to_string()} else {format!("addx {}\n", rng.gen_range(-12..=12))})
+            .collect()
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        register(input)
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (i as i32 + 1, x))
+            .filter(|(cycle, _)| [20, 60, 100, 140, 180, 220].contains(cycle))
+            .map(|(cycle, x)| cycle * x)
+            .sum::<i32>()
+            .into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        let during = register(input);
+
+        let mut screen = Picture::new(40, during.len().div_ceil(40));
+
+        for (i, x) in during.iter().enumerate() {
+            let (column, row) = (i % 40, i / 40);
+
+            screen.set(column, row, (column as i32 - x).abs() <= 1);
+        }
+
+        screen.into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day10>(2000);
+}
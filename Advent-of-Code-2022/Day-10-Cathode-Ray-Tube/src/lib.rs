@@ -1,12 +1,16 @@
+#[cfg(test)]
+mod fuzz;
+
 use nom::{
-    *,
     branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, newline}, multi::separated_list1,
+    character::complete,
 };
 
+use aoc_core::parse::{finish, lines, tag, IResult};
+use aoc_core::{Answer, ParseError, Picture, Solution};
+
 #[derive(Debug)]
-enum Instruction {
+pub enum Instruction {
     Addx(i32),
     Noop,
 }
@@ -34,89 +38,85 @@ fn noop(input: &str) -> IResult<&str, Instruction> {
 }
 
 fn instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
-    let (input, instructions) = separated_list1(newline, alt((addx, noop)))(input)?;
+    let (input, instructions) = lines(alt((addx, noop)))(input)?;
 
     Ok((input, instructions))
 }
 
-pub fn process_part_1(input: &str) -> String {
-    let instructions = instructions(input).unwrap().1;
+pub struct Day10;
 
-    let mut register_x = 1;
-    let mut current_cycle = 0;
+impl Solution for Day10 {
+    type Parsed = Vec<Instruction>;
 
-    let mut result = 0;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        finish(input, instructions)
+    }
 
-    instructions.iter().for_each(|instruction| {
-        (0..instruction.cycles()).for_each(|_| {
-            current_cycle += 1;
+    fn part_1(instructions: &Self::Parsed) -> Answer {
+        let mut register_x = 1;
+        let mut current_cycle = 0;
 
-            if current_cycle % 40 == 20 {result += current_cycle * register_x}
-        });
+        let mut result = 0;
 
-        match instruction {
-            Instruction::Addx(value) => register_x += value,
-            Instruction::Noop => {},
-        }
-    });
+        instructions.iter().for_each(|instruction| {
+            (0..instruction.cycles()).for_each(|_| {
+                current_cycle += 1;
 
-    return result.to_string();
-}
+                // Only the six cycles from the 20th to the 220th count, however long the program runs
+                if current_cycle % 40 == 20 && current_cycle <= 220 {result += current_cycle * register_x}
+            });
 
-pub fn process_part_2(input: &str) -> String {
-    let instructions = instructions(input).unwrap().1;
+            match instruction {
+                Instruction::Addx(value) => register_x += value,
+                Instruction::Noop => {},
+            }
+        });
 
-    let mut register_x = 1;
-    let mut position = 0;
+        return result.into();
+    }
 
-    let mut result = String::new();
+    fn part_2(instructions: &Self::Parsed) -> Answer {
+        let cycles: usize = instructions
+            .iter()
+            .map(|instruction| instruction.cycles())
+            .sum();
 
-    instructions.iter().for_each(|instruction| {
-        (0..instruction.cycles()).for_each(|_| {
-            if position == 40 {
-                result += "\n";
-                position = 0;
-            }
+        let mut register_x = 1;
+        let mut current_cycle = 0;
 
-            result += if position >= register_x - 1 && position <= register_x + 1 {"#"} else {"."};
+        let mut result = Picture::new(40, cycles.div_ceil(40));
 
-            position += 1;
-        });
+        instructions.iter().for_each(|instruction| {
+            (0..instruction.cycles()).for_each(|_| {
+                let position = (current_cycle % 40) as i32;
 
-        match instruction {
-            Instruction::Addx(value) => register_x += value,
-            Instruction::Noop => {},
-        }
-    });
+                if position >= register_x - 1 && position <= register_x + 1 {
+                    result.set(current_cycle % 40, current_cycle / 40, true);
+                }
 
-    result += "\n";
+                current_cycle += 1;
+            });
 
-    return result.to_string();
+            match instruction {
+                Instruction::Addx(value) => register_x += value,
+                Instruction::Noop => {},
+            }
+        });
+
+        return result.into();
+    }
 }
 
+aoc_core::examples!();
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use std::fs;
-
     #[test]
-    fn test_part_1() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
-
-        let result = process_part_1(&input);
-
-        assert_eq!(result, "13140");
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = fs::read_to_string("./test_input.txt").unwrap();
-
-        let result = process_part_2(&input);
-
-        let expected = fs::read_to_string("./test_output.txt").unwrap();
+    fn test_signal_strength_stops_at_220() {
+        let instructions = Day10::parse(&"noop\n".repeat(300)).unwrap();
 
-        assert_eq!(result, expected);
+        assert_eq!(Day10::part_1(&instructions), Answer::from(20 + 60 + 100 + 140 + 180 + 220));
     }
 }
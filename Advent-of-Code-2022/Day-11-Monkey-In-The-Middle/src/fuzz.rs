@@ -0,0 +1,129 @@
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::seq::SliceRandom;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day11;
+
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+struct Notes {
+    items: Vec<u64>,
+    operation: (String, String, String),
+    divisible: u64,
+    targets: (usize, usize),
+}
+
+fn notes(input: &str) -> Vec<Notes> {
+    input
+        .split("\n\n")
+        .map(|monkey| {
+            let lines: Vec<&str> = monkey.lines().map(str::trim).collect();
+            let last_number = |line: &str| line.rsplit(' ').next().unwrap().parse().unwrap();
+
+            let items = lines[1].trim_start_matches("Starting items: ").split(", ").map(|item| item.parse().unwrap()).collect();
+
+            let operation: Vec<String> = lines[2].trim_start_matches("Operation: new = ").split(' ').map(String::from).collect();
+
+            Notes {
+                items,
+                operation: (operation[0].clone(), operation[1].clone(), operation[2].clone()),
+                divisible: last_number(lines[3]),
+                targets: (last_number(lines[4]) as usize, last_number(lines[5]) as usize),
+            }
+        })
+        .collect()
+}
+
+// Plays the rounds one item at a time, giving up if a worry level no longer fits
+fn monkey_business(monkeys: &[Notes], rounds: usize, relief: bool) -> Option<u64> {
+    let modulus: u64 = monkeys.iter().map(|monkey| monkey.divisible).product();
+
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|monkey| monkey.items.clone()).collect();
+    let mut inspections = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for old in std::mem::take(&mut items[i]) {
+                inspections[i] += 1;
+
+                let value = |operand: &str| if operand == "old" {old} else {operand.parse().unwrap()};
+                let (a, operator, b) = &monkey.operation;
+
+                let new = match operator.as_str() {
+                    "+" => value(a).checked_add(value(b))?,
+                    "*" => value(a).checked_mul(value(b))?,
+                    _ => unreachable!(),
+                };
+
+                // Without relief only divisibility matters, and that survives taking the remainder by every divisor at once
+                let new = if relief {new / 3} else {new % modulus};
+
+                let target = if new % monkey.divisible == 0 {monkey.targets.0} else {monkey.targets.1};
+
+                items[target].push(new);
+            }
+        }
+    }
+
+    inspections.sort();
+
+    Some(inspections.iter().rev().take(2).product())
+}
+
+fn generate_notes(rng: &mut StdRng) -> String {
+    let count = rng.gen_range(2..=8);
+
+    let divisors: Vec<u64> = PRIMES.choose_multiple(rng, count).copied().collect();
+
+    let other = |rng: &mut StdRng, i: usize| (i + rng.gen_range(1..count)) % count;
+
+    (0..count)
+        .map(|i| {
+            let items: Vec<String> = (0..rng.gen_range(1..=4)).map(|_| rng.gen_range(1..=99).to_string()).collect();
+
+            let operation = match rng.gen_range(0..4) {
+                0 => "old * old".to_string(),
+                1 => "old + old".to_string(),
+                2 => format!("old * {}", rng.gen_range(2..=19)),
+                _ => format!("old + {}", rng.gen_range(1..=8)),
+            };
+
+            [
+                format!("Monkey {i}:"),
+                format!("  Starting items: {}", items.join(", ")),
+                format!("  Operation: new = {operation}"),
+                format!("  Test: divisible by {}", divisors[i]),
+                format!("    If true: throw to monkey {}", other(rng, i)),
+                format!("    If false: throw to monkey {}", other(rng, i)),
+            ]
+            .join("\n")
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n") + "\n"
+}
+
+impl Fuzz for Day11 {
+    fn generate(rng: &mut StdRng) -> String {
+        // Real notes never let a worry level overflow while relief is still keeping it down
+        loop {
+            let input = generate_notes(rng);
+
+            if monkey_business(&notes(&input), 20, true).is_some() {break input}
+        }
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        monkey_business(&notes(input), 20, true).unwrap().into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        monkey_business(&notes(input), 10_000, false).unwrap().into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day11>(100);
+}
@@ -1,34 +1,39 @@
+#[cfg(test)]
+mod fuzz;
+
 use std::collections::VecDeque;
 
-use nom::*;
+use nom::Parser;
 use nom::branch::alt;
-use nom::bytes::streaming::tag;
-use nom::character::complete::{newline, self, multispace1};
+use nom::character::complete::{self, multispace1};
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, preceded};
 
+use aoc_core::parse::{blocks, finish, tag, Error, IResult};
+use aoc_core::{Answer, ParseError, Solution};
 
-#[derive(Debug)]
-enum Value {
+
+#[derive(Clone, Debug)]
+pub enum Value {
     Old,
     Num(u64),
 }
 
-#[derive(Debug)]
-enum Operation {
+#[derive(Clone, Debug)]
+pub enum Operation {
     Add((Value, Value)),
     Mul((Value, Value)),
 }
 
-#[derive(Debug)]
-struct Test {
+#[derive(Clone, Debug)]
+pub struct Test {
     divisible: u64,
     on_true: u64,
     on_false: u64,
 }
 
-#[derive(Debug)]
-struct Monkey {
+#[derive(Clone, Debug)]
+pub struct Monkey {
     items: VecDeque<u64>,
     operation: Operation,
     test: Test,
@@ -53,7 +58,7 @@ impl Monkey {
                     Value::Num(num) => *num,
                 };
 
-                (num_a + num_b) % magic_trick
+                num_a + num_b
             },
             Operation::Mul((a, b)) => {
                 let num_a = match a {
@@ -66,20 +71,29 @@ impl Monkey {
                     Value::Num(num) => *num,
                 };
 
-                (num_a * num_b) % magic_trick
+                num_a * num_b
             }
         };
 
-        if relief {worry_level / 3} else {worry_level}
+        // Dividing by 3 doesn't commute with the remainder, so the worry level is only reduced once relief is gone
+        if relief {worry_level / 3} else {worry_level % magic_trick}
     }
 
     fn test(&self, item: u64) -> u64 {
-        if item % self.test.divisible == 0 {self.test.on_true} else {self.test.on_false}
+        if item.is_multiple_of(self.test.divisible) {self.test.on_true} else {self.test.on_false}
+    }
+}
+
+// Worry levels are kept modulo the product of the divisors, so a divisor of 0 is rejected here
+fn divisor(input: &str) -> IResult<&str, u64> {
+    match complete::u64(input)? {
+        (_, 0) => Err(nom::Err::Failure(Error::expected(input, "a divisor other than 0"))),
+        result => Ok(result),
     }
 }
 
 fn test(input: &str) -> IResult<&str, Test> {
-    let (input, divisible) = preceded(tag("Test: divisible by "), complete::u64)(input)?;
+    let (input, divisible) = preceded(tag("Test: divisible by "), divisor)(input)?;
     let (input, _) = multispace1(input)?;
     let (input, on_true) = preceded(tag("If true: throw to monkey "), complete::u64)(input)?;
     let (input, _) = multispace1(input)?;
@@ -89,7 +103,7 @@ fn test(input: &str) -> IResult<&str, Test> {
 }
 
 fn value(input: &str) -> IResult<&str, Value> {
-    let (input, value) = alt((tag("old").map(|_| Value::Old), complete::u64.map(|num| Value::Num(num))))(input)?;
+    let (input, value) = alt((tag("old").map(|_| Value::Old), complete::u64.map(Value::Num)))(input)?;
 
     Ok((input, value))
 }
@@ -117,95 +131,122 @@ fn monkey(input: &str) -> IResult<&str, Monkey> {
     let (input, operation) = operation(input)?;
     let (input, _) = multispace1(input)?;
     let (input, test) = test(input)?;
-    let (input, _) = newline(input)?;
 
     Ok((input, Monkey {items: VecDeque::from(items), operation, test, inspections: 0}))
 }
 
 fn parse_monkeys(input: &str) -> IResult<&str, Vec<Monkey>> {
-    let (input, monkeys) = separated_list1(newline, monkey)(input)?;
+    let (input, monkeys) = blocks(monkey)(input)?;
 
     Ok((input, monkeys))
 }
 
-pub fn process_part_1(input: &str) -> String {
-    let mut monkeys = parse_monkeys(input).unwrap().1;
-
-    let magic_trick: u64 = monkeys
-        .iter()
-        .map(|monkey| monkey.test.divisible)
-        .product();
-
-    (0..20).for_each(|_| {
-        (0..monkeys.len()).for_each(|monkey_index| {
-            (0..monkeys[monkey_index].items.len()).for_each(|_| {
-                let monkey = monkeys
-                    .get_mut(monkey_index)
-                    .unwrap();
-
-                let item = monkey.inspect(true, magic_trick);
-                let recipient = monkey.test(item);
-
-                monkeys
-                    .get_mut(recipient as usize)
-                    .unwrap()
-                    .items
-                    .push_back(item);
-            });
-        })
-    });
-
-    monkeys.sort_by_key(|monkey| monkey.inspections);
-
-    let result: u64 = monkeys
-        .iter()
-        .rev()
-        .take(2)
-        .map(|monkey| monkey.inspections)
-        .product();
-
-    return result.to_string();
-}
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let monkeys = finish(input, parse_monkeys)?;
+
+        let count = monkeys.len() as u64;
+
+        // Every item has to be thrown to a monkey that exists. The error points at the monkey's own throw line, which
+        // is the nth of its kind in the input
+        for (index, monkey) in monkeys.iter().enumerate() {
+            for (line, recipient) in [("If true: throw to monkey ", monkey.test.on_true), ("If false: throw to monkey ", monkey.test.on_false)] {
+                if recipient >= count {
+                    let offset = input.match_indices(line).nth(index).map_or(input.len(), |(i, _)| i + line.len());
+
+                    return Err(ParseError::at(input, offset, format!("a monkey from 0 to {}", count - 1)));
+                }
+            }
+        }
 
-pub fn process_part_2(input: &str) -> String {
-    let mut monkeys = parse_monkeys(input).unwrap().1;
-
-    let magic_trick: u64 = monkeys
-        .iter()
-        .map(|monkey| monkey.test.divisible)
-        .product();
-
-    (0..10_000).for_each(|_| {
-        (0..monkeys.len()).for_each(|monkey_index| {
-            (0..monkeys[monkey_index].items.len()).for_each(|_| {
-                let monkey = monkeys
-                    .get_mut(monkey_index)
-                    .unwrap();
-
-                let item = monkey.inspect(false, magic_trick);
-                let recipient = monkey.test(item);
-
-                monkeys
-                    .get_mut(recipient as usize)
-                    .unwrap()
-                    .items
-                    .push_back(item);
-            });
-        })
-    });
-
-    monkeys.sort_by_key(|monkey| monkey.inspections);
-
-    let result: u64 = monkeys
-        .iter()
-        .rev()
-        .take(2)
-        .map(|monkey| monkey.inspections)
-        .product();
-
-    return result.to_string();
+        Ok(monkeys)
+    }
+
+    fn part_1(monkeys: &Self::Parsed) -> Answer {
+        let mut monkeys = monkeys.clone();
+
+        let magic_trick: u64 = monkeys
+            .iter()
+            .map(|monkey| monkey.test.divisible)
+            .product();
+
+        (0..20).for_each(|_| {
+            (0..monkeys.len()).for_each(|monkey_index| {
+                (0..monkeys[monkey_index].items.len()).for_each(|_| {
+                    let monkey = monkeys
+                        .get_mut(monkey_index)
+                        .unwrap();
+
+                    let item = monkey.inspect(true, magic_trick);
+                    let recipient = monkey.test(item);
+
+                    monkeys
+                        .get_mut(recipient as usize)
+                        .unwrap()
+                        .items
+                        .push_back(item);
+                });
+            })
+        });
+
+        monkeys.sort_by_key(|monkey| monkey.inspections);
+
+        let result: u64 = monkeys
+            .iter()
+            .rev()
+            .take(2)
+            .map(|monkey| monkey.inspections)
+            .product();
+
+        return result.into();
+    }
+
+    fn part_2(monkeys: &Self::Parsed) -> Answer {
+        let mut monkeys = monkeys.clone();
+
+        let magic_trick: u64 = monkeys
+            .iter()
+            .map(|monkey| monkey.test.divisible)
+            .product();
+
+        (0..10_000).for_each(|_| {
+            (0..monkeys.len()).for_each(|monkey_index| {
+                (0..monkeys[monkey_index].items.len()).for_each(|_| {
+                    let monkey = monkeys
+                        .get_mut(monkey_index)
+                        .unwrap();
+
+                    let item = monkey.inspect(false, magic_trick);
+                    let recipient = monkey.test(item);
+
+                    monkeys
+                        .get_mut(recipient as usize)
+                        .unwrap()
+                        .items
+                        .push_back(item);
+                });
+            })
+        });
+
+        monkeys.sort_by_key(|monkey| monkey.inspections);
+
+        let result: u64 = monkeys
+            .iter()
+            .rev()
+            .take(2)
+            .map(|monkey| monkey.inspections)
+            .product();
+
+        return result.into();
+    }
 }
 
+aoc_core::examples!();
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,21 +254,36 @@ mod tests {
     const INPUT: &str = include_str!("../test_input.txt");
 
     #[test]
-    fn test_part_1() {
-        let result = process_part_1(INPUT);
+    fn test_relief_before_remainder() {
+        let mut monkey = Monkey {
+            items: VecDeque::from([79]),
+            operation: Operation::Mul((Value::Old, Value::Num(19))),
+            test: Test {divisible: 7, on_true: 0, on_false: 0},
+            inspections: 0,
+        };
+
+        // 79 * 19 = 1501, which is 500 after relief. Taking the remainder by 7 first would leave 1
+        assert_eq!(monkey.inspect(true, 7), 500);
+    }
 
-        let expected = "10605";
+    #[test]
+    fn test_missing_recipient() {
+        // Only the first two monkeys, the first of which throws to monkeys 2 and 3
+        let input = INPUT.split("\n\n").take(2).collect::<Vec<_>>().join("\n\n");
+
+        let error = Day11::parse(&input).unwrap_err();
 
-        assert_eq!(result, expected);
+        assert_eq!((error.line(), error.column()), (5, 30));
+        assert_eq!(error.expected(), "a monkey from 0 to 1");
     }
 
     #[test]
-    fn test_part_2() {
-        let result = process_part_2(INPUT);
+    fn test_zero_divisor() {
+        let input = INPUT.replace("divisible by 19", "divisible by 0");
 
-        let expected = "2713310158";
+        let error = Day11::parse(&input).unwrap_err();
 
-        assert_eq!(result, expected);
+        assert_eq!((error.line(), error.column()), (11, 22));
+        assert_eq!(error.expected(), "a divisor other than 0");
     }
 }
-
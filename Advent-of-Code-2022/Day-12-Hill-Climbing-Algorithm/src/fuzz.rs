@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day12;
+
+fn elevation(c: char) -> u8 {
+    match c {
+        'S' => b'a',
+        'E' => b'z',
+        c => c as u8,
+    }
+}
+
+fn rows(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+fn find(rows: &[Vec<char>], marker: char) -> (usize, usize) {
+    (0..rows.len())
+        .flat_map(|y| (0..rows[y].len()).map(move |x| (x, y)))
+        .find(|&(x, y)| rows[y][x] == marker)
+        .unwrap()
+}
+
+// Plain breadth-first search from `start` to the end, climbing at most one step at a time
+fn steps(rows: &[Vec<char>], start: (usize, usize)) -> Option<usize> {
+    let mut distance = vec![vec![None; rows[0].len()]; rows.len()];
+    let mut queue = VecDeque::from([start]);
+
+    distance[start.1][start.0] = Some(0);
+
+    while let Some((x, y)) = queue.pop_front() {
+        let here = distance[y][x].unwrap();
+
+        if rows[y][x] == 'E' {
+            return Some(here);
+        }
+
+        let neighbours = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+
+        for (nx, ny) in neighbours {
+            if ny < rows.len() && nx < rows[0].len() && distance[ny][nx].is_none() && elevation(rows[ny][nx]) <= elevation(rows[y][x]) + 1 {
+                distance[ny][nx] = Some(here + 1);
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+
+    None
+}
+
+impl Fuzz for Day12 {
+    fn generate(rng: &mut StdRng) -> String {
+        loop {
+            let (width, height): (usize, usize) = (rng.gen_range(10..=30), rng.gen_range(3..=10));
+            let (end_x, end_y) = (rng.gen_range(0..width), rng.gen_range(0..height));
+
+            // Elevation rises a step at a time towards the end, with dips and the odd cliff to route around
+            let mut rows: Vec<Vec<char>> = (0..height)
+                .map(|y| {
+                    (0..width)
+                        .map(|x| {
+                            let distance = x.abs_diff(end_x) + y.abs_diff(end_y);
+                            let elevation = 25usize.saturating_sub(distance + rng.gen_range(0..=1));
+                            let elevation = if rng.gen_bool(0.1) {(elevation + 3).min(25)} else {elevation};
+
+                            (b'a' + elevation as u8) as char
+                        })
+                        .collect()
+                })
+                .collect();
+
+            rows[end_y][end_x] = 'E';
+
+            let (start_x, start_y) = (rng.gen_range(0..width), rng.gen_range(0..height));
+
+            if rows[start_y][start_x] == 'E' {
+                continue;
+            }
+
+            rows[start_y][start_x] = 'S';
+
+            if steps(&rows, (start_x, start_y)).is_some() {
+                break rows.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+            }
+        }
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        let rows = rows(input);
+
+        steps(&rows, find(&rows, 'S')).unwrap().into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        let rows = rows(input);
+
+        // Search again from every lowest square
+        (0..rows.len())
+            .flat_map(|y| (0..rows[y].len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| elevation(rows[y][x]) == b'a')
+            .filter_map(|start| steps(&rows, start))
+            .min()
+            .unwrap()
+            .into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day12>(200);
+}
@@ -1,183 +1,92 @@
-use std::iter;
-
-use itertools::Itertools;
-
-use nom::*;
-use nom::character::complete::{newline, alpha1};
-use nom::multi::separated_list1;
+#[cfg(test)]
+mod fuzz;
+mod visual;
 
 use petgraph::algo::dijkstra;
 use petgraph::prelude::*;
 
-fn height_map(input: &str) -> IResult<&str, Vec<Vec<char>>> {
-    separated_list1(newline, alpha1.map(|line: &str| line.chars().collect()))(input)
-}
+use aoc_core::{Answer, ParseError, Solution};
+use aoc_grid::{Grid, Position};
 
-pub fn process_part_1(input: &str) -> String {
-    let height_map = height_map(input).unwrap().1;
-
-    let start = height_map
-        .iter()
-        .enumerate()
-        .flat_map(|(i, char)| {
-            char.iter()
-                .enumerate()
-                .zip(iter::repeat(i))
-        })
-        .find_map(|((x, &c), y)| if c == 'S' {Some((x as i32, y as i32))} else {None})
-        .unwrap();
-
-    let end = height_map
-        .iter()
-        .enumerate()
-        .flat_map(|(i, char)| {
-            char.iter()
-                .enumerate()
-                .zip(iter::repeat(i))
-        })
-        .find_map(|((x, &c), y)| if c == 'E' {Some((x as i32, y as i32))} else {None})
-        .unwrap();
-
-    let height_map: Vec<Vec<char>> = height_map
-        .iter()
-        .map(|vec| {
-            vec.iter()
-                .map(|c| {
-                    match c {
-                        'S' => 'a',
-                        'E' => 'z',
-                        v => *v,
-                    }
-                })
-                .collect()
-        }).collect();
-
-    let edges = (0i32..height_map.len() as i32)
-        .cartesian_product(0i32..height_map[0].len() as i32)
-        .flat_map(|(y, x)| {
-            let current_node = (x, y);
-
-            let possible_neighbours = vec![
-                (x - 1, y),
-                (x + 1, y),
-                (x, y - 1),
-                (x, y + 1),
-            ];
-
-            possible_neighbours
-                .iter()
-                .filter_map(|cell| {
-                    height_map
-                        .get(cell.1 as usize)
-                        .and_then(|vec| vec.get(cell.0 as usize))
-                        .and_then(|valid_cell| {
-                            let current_node_height = height_map[y as usize][x as usize];
-
-                            if current_node_height as u8 + 1 >= *valid_cell as u8 {Some((current_node, *cell))} else {None}
-                        })
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<((i32, i32), (i32, i32))>>();
-
-    let graph = DiGraphMap::<(i32, i32), ()>::from_edges(&edges);
-
-    let paths = dijkstra(&graph, start, Some(end), |_| 1);
-
-    let result = paths[&(end.0, end.1)];
-
-    return result.to_string();
+pub struct HeightMap {
+    start: Position,
+    end: Position,
+    heights: Grid<char>,
 }
 
-pub fn process_part_2(input: &str) -> String {
-    let height_map = height_map(input).unwrap().1;
-
-    let end = height_map
-        .iter()
-        .enumerate()
-        .flat_map(|(i, char)| {
-            char.iter()
-                .enumerate()
-                .zip(iter::repeat(i))
-        })
-        .find_map(|((x, &c), y)| if c == 'E' {Some((x as i32, y as i32))} else {None})
-        .unwrap();
-
-    let height_map: Vec<Vec<char>> = height_map
-        .iter()
-        .map(|vec| {
-            vec.iter()
-                .map(|c| {
-                    match c {
-                        'S' => 'a',
-                        'E' => 'z',
-                        v => *v,
-                    }
-                })
-                .collect()
-        }).collect();
-
-    let edges = (0i32..height_map.len() as i32)
-        .cartesian_product(0i32..height_map[0].len() as i32)
-        .flat_map(|(y, x)| {
-            let current_node = (x, y);
-
-            let possible_neighbours = vec![
-                (x - 1, y),
-                (x + 1, y),
-                (x, y - 1),
-                (x, y + 1),
-            ];
-
-            possible_neighbours
-                .iter()
-                .filter_map(|cell| {
-                    height_map
-                        .get(cell.1 as usize)
-                        .and_then(|vec| vec.get(cell.0 as usize))
-                        .and_then(|valid_cell| {
-                            let current_node_height = height_map[y as usize][x as usize];
-
-                            if current_node_height as u8 + 1 >= *valid_cell as u8 {Some((current_node, *cell))} else {None}
-                        })
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<((i32, i32), (i32, i32))>>();
-
-    let graph = DiGraphMap::<(i32, i32), ()>::from_edges(
-        edges.iter().map(|(a, b)| (*b, *a))
-    );
-
-    let paths = dijkstra(&graph, end, None, |_| 1);
-
-    let result = paths
-        .iter()
-        .filter_map(|((x, y), steps)| if height_map[*y as usize][*x as usize] == 'a' {Some(steps)} else {None})
-        .min()
-        .unwrap();
-
-    return result.to_string();
+impl HeightMap {
+    fn edges(&self) -> Vec<(Position, Position)> {
+        let heights = &self.heights;
+
+        heights
+            .positions()
+            .flat_map(|current_node| {
+                heights
+                    .neighbours_4(current_node)
+                    .filter(move |&cell| heights[current_node] as u8 + 1 >= heights[cell] as u8)
+                    .map(move |cell| (current_node, cell))
+            })
+            .collect()
+    }
+
+    // Reversed, the graph leads from the end back to every cell that can reach it
+    fn graph(&self, reverse: bool) -> DiGraphMap<Position, ()> {
+        DiGraphMap::from_edges(self.edges().into_iter().map(|(a, b)| if reverse {(b, a)} else {(a, b)}))
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let height_map = Grid::parse(input, |c| if c.is_ascii_lowercase() || c == 'S' || c == 'E' {Some(c)} else {None})?;
 
-    const INPUT: &str = include_str!("../test_input.txt");
+        let start = height_map
+            .find(|&c| c == 'S')
+            .ok_or_else(|| ParseError::at(input, input.len(), "a start marked with 'S'"))?;
 
-    #[test]
-    fn test_part_1() {
-        let result = process_part_1(INPUT);
+        let end = height_map
+            .find(|&c| c == 'E')
+            .ok_or_else(|| ParseError::at(input, input.len(), "an end marked with 'E'"))?;
 
-        assert_eq!(result, "31");
+        let heights = height_map.map(|c| {
+            match c {
+                'S' => 'a',
+                'E' => 'z',
+                v => *v,
+            }
+        });
+
+        Ok(HeightMap {start, end, heights})
     }
 
-    #[test]
-    fn test_part_2() {
-        let result = process_part_2(INPUT);
+    fn part_1(height_map: &Self::Parsed) -> Answer {
+        let HeightMap {start, end, ..} = *height_map;
+
+        let graph = height_map.graph(false);
+
+        let paths = dijkstra(&graph, start, Some(end), |_| 1);
+
+        let result = paths[&end];
+
+        return result.into();
+    }
+
+    fn part_2(height_map: &Self::Parsed) -> Answer {
+        let graph = height_map.graph(true);
+
+        let paths = dijkstra(&graph, height_map.end, None, |_| 1);
+
+        let result = paths
+            .iter()
+            .filter_map(|(&position, steps)| if height_map.heights[position] == 'a' {Some(*steps)} else {None})
+            .min()
+            .unwrap();
 
-        assert_eq!(result, "29");
+        return result.into();
     }
 }
 
+aoc_core::examples!();
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use petgraph::algo::dijkstra;
+
+use aoc_grid::Position;
+use aoc_visual::{Color, Frame, Recorder, Visualize};
+
+use crate::{Day12, HeightMap};
+
+const LOW: Color = Color::rgb(20, 70, 30);
+const HIGH: Color = Color::rgb(240, 240, 240);
+const EXPLORED: Color = Color::rgb(40, 110, 220);
+const PATH: Color = Color::rgb(255, 60, 40);
+
+fn terrain(height_map: &HeightMap) -> Frame {
+    height_map.heights.map(|&height| LOW.lerp(HIGH, (height as u8 - b'a') as f64 / 25.0))
+}
+
+// The search spreading out one step at a time over the terrain, then the shortest path traced back from where it ends
+impl Visualize for Day12 {
+    fn visualize(height_map: &Self::Parsed, part: u8, recorder: &mut dyn Recorder) {
+        let reverse = part == 2;
+
+        let graph = height_map.graph(reverse);
+
+        let steps: HashMap<Position, usize> = dijkstra(&graph, if reverse {height_map.end} else {height_map.start}, None, |_| 1);
+
+        let target = match reverse {
+            false => height_map.end,
+            true => *steps
+                .keys()
+                .filter(|&&position| height_map.heights[position] == 'a')
+                .min_by_key(|&position| steps[position])
+                .unwrap(),
+        };
+
+        let mut frame = terrain(height_map);
+
+        (0..=steps[&target]).for_each(|layer| {
+            steps
+                .iter()
+                .filter(|&(_, &distance)| distance == layer)
+                .for_each(|(&position, _)| frame[position] = frame[position].lerp(EXPLORED, 0.6));
+
+            recorder.record(&frame);
+        });
+
+        // Walking back down the layers, any neighbour one step closer to where the search began is on a shortest path
+        let mut position = target;
+
+        frame[position] = PATH;
+
+        while steps[&position] > 0 {
+            position = height_map.heights
+                .neighbours_4(position)
+                .find(|&previous| steps.get(&previous) == Some(&(steps[&position] - 1)) && graph.contains_edge(previous, position))
+                .unwrap();
+
+            frame[position] = PATH;
+
+            recorder.record(&frame);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_visualize() {
+        let height_map = Day12::parse_input(include_str!("../test_input.txt")).unwrap();
+
+        let mut frames = vec![];
+
+        Day12::visualize(&height_map, 1, &mut frames);
+
+        let path = frames.last().unwrap().iter().filter(|(_, &color)| color == PATH).count();
+
+        // A frame for each of the 32 layers the search goes through, then one for each step back along the path
+        assert_eq!(frames.len(), 32 + 31);
+        assert_eq!(path, 32);
+    }
+}
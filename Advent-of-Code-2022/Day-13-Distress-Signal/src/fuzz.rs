@@ -0,0 +1,135 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day13;
+
+#[derive(Clone, Debug)]
+enum Value {
+    Integer(u32),
+    List(Vec<Value>),
+}
+
+fn parse_value(chars: &mut Peekable<Chars>) -> Value {
+    if chars.peek() == Some(&'[') {
+        chars.next();
+
+        let mut list = vec![];
+
+        while chars.peek() != Some(&']') {
+            list.push(parse_value(chars));
+
+            if chars.peek() == Some(&',') {
+                chars.next();
+            }
+        }
+
+        chars.next();
+
+        Value::List(list)
+    } else {
+        let mut digits = String::new();
+
+        while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+            digits.push(digit);
+        }
+
+        Value::Integer(digits.parse().unwrap())
+    }
+}
+
+fn packet(line: &str) -> Value {
+    parse_value(&mut line.chars().peekable())
+}
+
+// The comparison exactly as the puzzle describes it
+fn compare(left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+        (Value::List(a), Value::List(b)) => {
+            for i in 0..a.len().min(b.len()) {
+                let ordering = compare(&a[i], &b[i]);
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+
+            a.len().cmp(&b.len())
+        },
+        (Value::Integer(a), list) => compare(&Value::List(vec![Value::Integer(*a)]), list),
+        (list, Value::Integer(b)) => compare(list, &Value::List(vec![Value::Integer(*b)])),
+    }
+}
+
+fn generate_value(rng: &mut StdRng, depth: usize) -> Value {
+    if depth > 0 && rng.gen_bool(0.4) {
+        Value::Integer(rng.gen_range(0..=10))
+    } else {
+        Value::List((0..rng.gen_range(0..=if depth < 3 {4} else {0})).map(|_| generate_value(rng, depth + 1)).collect())
+    }
+}
+
+fn show(value: &Value) -> String {
+    match value {
+        Value::Integer(integer) => integer.to_string(),
+        Value::List(list) => format!("[{}]", list.iter().map(show).collect::<Vec<String>>().join(",")),
+    }
+}
+
+impl Fuzz for Day13 {
+    fn generate(rng: &mut StdRng) -> String {
+        let pairs: Vec<String> = (0..rng.gen_range(1..=20))
+            .map(|_| {
+                // No pair in a real signal is ever in a tie
+                let left = generate_value(rng, 0);
+                let right = loop {
+                    let right = generate_value(rng, 0);
+
+                    if compare(&left, &right) != Ordering::Equal {break right}
+                };
+
+                format!("{}\n{}", show(&left), show(&right))
+            })
+            .collect();
+
+        pairs.join("\n\n") + "\n"
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        input
+            .split("\n\n")
+            .enumerate()
+            .filter(|(_, pair)| {
+                let (left, right) = pair.trim().split_once('\n').unwrap();
+
+                compare(&packet(left), &packet(right)) == Ordering::Less
+            })
+            .map(|(i, _)| i + 1)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        let packets: Vec<Value> = input.lines().filter(|line| !line.is_empty()).map(packet).collect();
+
+        // A divider's position in the sorted list is one more than the number of packets that sort before it
+        let position = |divider: &str| {
+            let divider = packet(divider);
+
+            1 + packets.iter().filter(|packet| compare(packet, &divider) == Ordering::Less).count()
+        };
+
+        (position("[[2]]") * (position("[[6]]") + 1)).into()
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day13>(2000);
+}
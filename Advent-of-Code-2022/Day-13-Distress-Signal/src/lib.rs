@@ -1,14 +1,19 @@
+#[cfg(test)]
+mod fuzz;
+
 use std::cmp::Ordering;
 
-use nom::*;
+use nom::Parser;
 use nom::branch::alt;
-use nom::bytes::streaming::tag;
-use nom::character::complete::{self, newline};
-use nom::multi::{separated_list0, separated_list1};
+use nom::character::complete;
+use nom::multi::separated_list0;
 use nom::sequence::{delimited, separated_pair};
 
+use aoc_core::parse::{blocks, eol, finish, tag, IResult};
+use aoc_core::{Answer, ParseError, Solution};
+
 #[derive(Debug, Eq)]
-enum Packet {
+pub enum Packet {
     List(Vec<Packet>),
     Integer(u32),
 }
@@ -19,7 +24,7 @@ impl Ord for Packet {
             (Self::List(a), Self::List(b)) => a.cmp(b),
             (Self::List(a), Self::Integer(b)) => a.cmp(&vec![Packet::Integer(*b)]),
             (Self::Integer(a), Self::Integer(b)) => a.cmp(b),
-            (Self::Integer(a), Self::List(b)) => vec![Packet::Integer(*a)].cmp(&b),
+            (Self::Integer(a), Self::List(b)) => vec![Packet::Integer(*a)].cmp(b),
         }
     }
 }
@@ -43,23 +48,22 @@ impl PartialOrd for Packet {
 
 
 #[derive(Debug)]
-struct Pair {
+pub struct Pair {
     left: Packet,
     right: Packet,
 }
 
 fn packet(input: &str) -> IResult<&str, Packet> {
     let (input, packet) = alt((
-        delimited(tag("["), separated_list0(tag(","), packet), tag("]")).map(|list| Packet::List(list)),
-        complete::u32.map(|integer| Packet::Integer(integer)),
+        delimited(tag("["), separated_list0(tag(","), packet), tag("]")).map(Packet::List),
+        complete::u32.map(Packet::Integer),
     ))(input)?;
 
     Ok((input, packet))
 }
 
 fn packet_pair(input: &str) -> IResult<&str, Pair> {
-    let (input, pair) = separated_pair(packet, newline, packet)(input)?;
-    let (input, _) = newline(input)?;
+    let (input, pair) = separated_pair(packet, eol, packet)(input)?;
 
     let packet_pair = Pair {
         left: pair.0,
@@ -70,79 +74,65 @@ fn packet_pair(input: &str) -> IResult<&str, Pair> {
 }
 
 fn parse_signal(input: &str) -> IResult<&str, Vec<Pair>> {
-    let (input, packet_pairs) = separated_list1(newline, packet_pair)(input)?;
+    let (input, packet_pairs) = blocks(packet_pair)(input)?;
 
     Ok((input, packet_pairs))
 }
 
-pub fn process_part_1(input: &str) -> String {
-    let distress_signal = parse_signal(input).unwrap().1;
-
-    let result: u32 = distress_signal
-        .iter()
-        .enumerate()
-        .filter_map(|(i, Pair {left, right})| {
-            match left.cmp(right) {
-                Ordering::Less => Some(i as u32),
-                Ordering::Equal => panic!("How are they equal!?"),
-                Ordering::Greater => None,
-            }
-        })
-        .map(|i| i + 1)
-        .sum();
-
-    return result.to_string();
-}
-
-pub fn process_part_2(input: &str) -> String {
-    let distress_signal = parse_signal(input).unwrap().1;
-
-    let divider_packet_2 = Packet::List(vec![Packet::List(vec![Packet::Integer(2)])]);
-    let divider_packet_6 = Packet::List(vec![Packet::List(vec![Packet::Integer(6)])]);
-
-    let mut packets: Vec<&Packet> = distress_signal
-        .iter()
-        .flat_map(|Pair {left, right}| [left, right])
-        .chain([&divider_packet_2, &divider_packet_6])
-        .collect();
+pub struct Day13;
 
-    packets.sort();
+impl Solution for Day13 {
+    type Parsed = Vec<Pair>;
 
-    let index_2 = packets
-        .iter()
-        .enumerate()
-        .find_map(|(i, &packet)| if packet == &divider_packet_2 {Some(i + 1)} else {None})
-        .unwrap();
-
-    let index_6 = packets
-        .iter()
-        .enumerate()
-        .find_map(|(i, &packet)| if packet == &divider_packet_6 {Some(i + 1)} else {None})
-        .unwrap();
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        finish(input, parse_signal)
+    }
 
-    let result = index_2 * index_6;
+    fn part_1(distress_signal: &Self::Parsed) -> Answer {
+        let result: u32 = distress_signal
+            .iter()
+            .enumerate()
+            .filter_map(|(i, Pair {left, right})| {
+                match left.cmp(right) {
+                    Ordering::Less => Some(i as u32),
+                    Ordering::Equal => panic!("How are they equal!?"),
+                    Ordering::Greater => None,
+                }
+            })
+            .map(|i| i + 1)
+            .sum();
+
+        return result.into();
+    }
 
-    return result.to_string();
-}
+    fn part_2(distress_signal: &Self::Parsed) -> Answer {
+        let divider_packet_2 = Packet::List(vec![Packet::List(vec![Packet::Integer(2)])]);
+        let divider_packet_6 = Packet::List(vec![Packet::List(vec![Packet::Integer(6)])]);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let mut packets: Vec<&Packet> = distress_signal
+            .iter()
+            .flat_map(|Pair {left, right}| [left, right])
+            .chain([&divider_packet_2, &divider_packet_6])
+            .collect();
 
-    const INPUT: &str = include_str!("../test_input.txt");
+        packets.sort();
 
-    #[test]
-    fn test_part_1() {
-        let result = process_part_1(INPUT);
+        let index_2 = packets
+            .iter()
+            .enumerate()
+            .find_map(|(i, &packet)| if packet == &divider_packet_2 {Some(i + 1)} else {None})
+            .unwrap();
 
-        assert_eq!(result, "13");
-    }
+        let index_6 = packets
+            .iter()
+            .enumerate()
+            .find_map(|(i, &packet)| if packet == &divider_packet_6 {Some(i + 1)} else {None})
+            .unwrap();
 
-    #[test]
-    fn test_part_2() {
-        let result = process_part_2(INPUT);
+        let result = index_2 * index_6;
 
-        assert_eq!(result, "140");
+        return result.into();
     }
 }
 
+aoc_core::examples!();
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use aoc_core::Answer;
+use aoc_fuzz::rand::rngs::StdRng;
+use aoc_fuzz::rand::Rng;
+use aoc_fuzz::{differential, Fuzz};
+
+use crate::Day14;
+
+fn rocks(input: &str) -> HashSet<(i32, i32)> {
+    let mut rocks = HashSet::new();
+
+    for line in input.lines() {
+        let corners: Vec<(i32, i32)> = line
+            .split(" -> ")
+            .map(|corner| {
+                let (x, y) = corner.split_once(',').unwrap();
+
+                (x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect();
+
+        for pair in corners.windows(2) {
+            let ((ax, ay), (bx, by)) = (pair[0], pair[1]);
+
+            for x in ax.min(bx)..=ax.max(bx) {
+                for y in ay.min(by)..=ay.max(by) {
+                    rocks.insert((x, y));
+                }
+            }
+        }
+    }
+
+    rocks
+}
+
+// Drops one grain at a time from the source, with an optional floor, until one falls past the lowest rock or the
+// source itself is buried
+fn resting_sand(input: &str, floor: bool) -> Answer {
+    let mut blocked = rocks(input);
+
+    let lowest = blocked.iter().map(|&(_, y)| y).max().unwrap();
+    let floor = if floor {Some(lowest + 2)} else {None};
+
+    let mut resting = 0;
+
+    while !blocked.contains(&(500, 0)) {
+        let (mut x, mut y) = (500, 0);
+
+        loop {
+            if floor.is_none() && y > lowest {
+                return resting.into();
+            }
+
+            let next = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)]
+                .into_iter()
+                .find(|&(nx, ny)| !blocked.contains(&(nx, ny)) && Some(ny) != floor);
+
+            match next {
+                Some(position) => (x, y) = position,
+                None => break,
+            }
+        }
+
+        blocked.insert((x, y));
+        resting += 1;
+    }
+
+    resting.into()
+}
+
+impl Fuzz for Day14 {
+    fn generate(rng: &mut StdRng) -> String {
+        (0..rng.gen_range(1..=8))
+            .map(|_| {
+                let mut corner: (i32, i32) = (rng.gen_range(485..=515), rng.gen_range(1..=20));
+                let mut corners = vec![corner];
+
+                // Paths turn at every corner, alternating horizontal and vertical runs
+                let mut horizontal = rng.gen_bool(0.5);
+
+                for _ in 0..rng.gen_range(1..=4) {
+                    let length = rng.gen_range(-6..=6);
+
+                    corner = if horizontal {(corner.0 + length, corner.1)} else {(corner.0, (corner.1 + length).max(1))};
+                    horizontal = !horizontal;
+
+                    corners.push(corner);
+                }
+
+                corners.iter().map(|(x, y)| format!("{x},{y}")).collect::<Vec<String>>().join(" -> ") + "\n"
+            })
+            .collect()
+    }
+
+    fn reference_1(input: &str) -> Answer {
+        resting_sand(input, false)
+    }
+
+    fn reference_2(input: &str) -> Answer {
+        resting_sand(input, true)
+    }
+}
+
+#[test]
+fn test_against_reference() {
+    differential::<Day14>(500);
+}
@@ -1,14 +1,91 @@
-use std::collections::BTreeSet;
+#[cfg(test)]
+mod fuzz;
+mod visual;
+
+use std::fmt;
 
 use itertools::Itertools;
 
-use nom::*;
-use nom::bytes::streaming::tag;
-use nom::character::complete::{self, line_ending};
+use nom::character::complete;
 use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
 
-fn rock_path(input: &str) -> IResult<&str, BTreeSet<(u32, u32)>> {
+use aoc_core::parse::{finish, lines, tag, IResult};
+use aoc_core::{Answer, ParseError, Solution};
+use aoc_grid::{BoundingBox, Grid, Point, Position};
+
+const SOURCE: Point<u32> = Point::new(500, 0);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+#[derive(Clone)]
+pub struct Cave {
+    tiles: Grid<Tile>,
+    source: Position,
+}
+
+impl Cave {
+    // Anything falling into the row between the lowest rock and the floor is gone for good
+    fn abyss(&self) -> usize {
+        self.tiles.height() - 2
+    }
+
+    // Lays rock along the bottom row, returning which row that is
+    fn add_floor(&mut self) -> usize {
+        let floor = self.tiles.height() - 1;
+
+        (0..self.tiles.width()).for_each(|x| self.tiles[Point::new(x, floor)] = Tile::Rock);
+
+        floor
+    }
+
+    // Drops sand until a grain falls past `abyss` or the source is blocked, returning how many grains came to rest.
+    // `rested` sees the cave each time a grain settles
+    fn pour(&mut self, abyss: usize, mut rested: impl FnMut(&Grid<Tile>)) -> usize {
+        let mut resting = 0;
+
+        loop {
+            let mut sand = self.source;
+
+            loop {
+                if sand.y >= abyss {return resting}
+
+                let Point {x, y} = sand;
+
+                match [Point::new(x, y + 1), Point::new(x - 1, y + 1), Point::new(x + 1, y + 1)].into_iter().find(|&below| self.tiles[below] == Tile::Air) {
+                    Some(below) => sand = below,
+                    None => break,
+                }
+            }
+
+            self.tiles[sand] = Tile::Sand;
+            resting += 1;
+
+            rested(&self.tiles);
+
+            if sand == self.source {return resting}
+        }
+    }
+}
+
+fn rock_path(input: &str) -> IResult<&str, Vec<Point<u32>>> {
     let (input, rock_path) = separated_list1(tag(" -> "), separated_pair(complete::u32, tag(","), complete::u32))(input)?;
 
     let rocks = rock_path
@@ -23,15 +100,17 @@ fn rock_path(input: &str) -> IResult<&str, BTreeSet<(u32, u32)>> {
             let y_max = ay.max(by);
             let y_range = y_min..=y_max;
 
-            x_range.cartesian_product(y_range)
+            x_range
+                .cartesian_product(y_range)
+                .map(Point::from)
         })
         .collect();
 
     Ok((input, rocks))
 }
 
-fn rocks(input: &str) -> IResult<&str, BTreeSet<(u32, u32)>> {
-    let (input, rocks) = separated_list1(line_ending, rock_path)(input)?;
+fn rocks(input: &str) -> IResult<&str, Vec<Point<u32>>> {
+    let (input, rocks) = lines(rock_path)(input)?;
 
     let rocks = rocks
         .into_iter()
@@ -41,130 +120,68 @@ fn rocks(input: &str) -> IResult<&str, BTreeSet<(u32, u32)>> {
     Ok((input, rocks))
 }
 
-pub fn process_part_1(input: &str) -> String {
-    let mut rocks = rocks(input).unwrap().1;
+pub struct Day14;
 
-    let rock_count = rocks.len();
+impl Solution for Day14 {
+    type Parsed = Cave;
 
-    let mut rocks_vec: Vec<(u32, u32)> = rocks
-        .clone()
-        .into_iter()
-        .collect();
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let rocks = finish(input, rocks)?;
 
-    rocks_vec.sort_by(|a, b| a.1.cmp(&b.1));
+        // Sand can spread at most one column sideways per row, so with the floor two rows below the lowest rock
+        // the grid only needs to be as wide as that triangle (or the rocks, if they stick out further). A floor more
+        // than 500 rows down puts the triangle's left edge past x = 0, so the bounds are signed
+        let signed = |point: Point<u32>| Point::new(point.x as i64, point.y as i64);
 
-    let lowest_rock = rocks_vec
-        .last()
-        .unwrap();
+        let mut bounds = BoundingBox::from_points(rocks.iter().copied().map(signed)).unwrap();
 
-    let mut sand = (500, 0);
+        let (source, floor) = (signed(SOURCE), bounds.max.y + 2);
 
-    loop {
-        if sand.1 > lowest_rock.1 {break}
+        bounds.include(Point::new(source.x - floor, floor));
+        bounds.include(Point::new(source.x + floor, source.y));
 
-        let down = (sand.0, sand.1 + 1);
-        let left = (sand.0 - 1, sand.1 + 1);
-        let right = (sand.0 + 1, sand.1 + 1);
+        let to_position = |point: Point<u32>| Point::new((signed(point).x - bounds.min.x) as usize, point.y as usize);
 
-        let down_check = rocks.get(&down);
-        let left_check = rocks.get(&left);
-        let right_check = rocks.get(&right);
+        let mut tiles = Grid::filled(bounds.width() as usize, floor as usize + 1, Tile::Air);
 
-        match (down_check, left_check, right_check) {
-            (None, _, _) => sand = down,
-            (_, None, _) => sand = left,
-            (_, _, None) => sand = right,
-            (Some(_), Some(_), Some(_)) => {
-                rocks.insert(sand);
+        rocks.iter().for_each(|&rock| tiles[to_position(rock)] = Tile::Rock);
 
-                sand = (500, 0)
-            },
-        }
+        Ok(Cave {tiles, source: to_position(SOURCE)})
     }
 
-    let result = rocks.len() - rock_count;
-
-    return result.to_string();
-}
-
-pub fn process_part_2(input: &str) -> String {
-    let mut rocks = rocks(input).unwrap().1;
-
-    let rock_count = rocks.len();
-
-    let mut rocks_vec: Vec<(u32, u32)> = rocks
-        .clone()
-        .into_iter()
-        .collect();
-
-    rocks_vec.sort_by(|a, b| a.1.cmp(&b.1));
-
-    let lowest_rock = rocks_vec
-        .last()
-        .unwrap();
-
-    let floor = lowest_rock.1 + 2;
+    fn part_1(cave: &Self::Parsed) -> Answer {
+        let mut cave = cave.clone();
 
-    let mut sand = (500, 0);
+        let result = cave.pour(cave.abyss(), |_| {});
 
-    while let None = rocks.get(&(500, 0)) {
-        let down = (sand.0, sand.1 + 1);
-        let left = (sand.0 - 1, sand.1 + 1);
-        let right = (sand.0 + 1, sand.1 + 1);
-
-        let down_check = rocks
-            .get(&down)
-            .or_else(|| {
-                if down.1 == floor {Some(&lowest_rock)} else {None}
-            });
+        return result.into();
+    }
 
-        let left_check = rocks
-            .get(&left)
-            .or_else(|| {
-                if left.1 == floor {Some(&lowest_rock)} else {None}
-            });
+    fn part_2(cave: &Self::Parsed) -> Answer {
+        let mut cave = cave.clone();
 
-        let right_check = rocks
-            .get(&right)
-            .or_else(|| {
-                if right.1 == floor {Some(&lowest_rock)} else {None}
-            });
+        let floor = cave.add_floor();
 
-        match (down_check, left_check, right_check) {
-            (Some(_), Some(_), Some(_)) => {
-                rocks.insert(sand);
+        let result = cave.pour(floor, |_| {});
 
-                sand = (500, 0)
-            },
-            (None, _, _) => sand = down,
-            (_, None, _) => sand = left,
-            (_, _, None) => sand = right,
-        }
+        return result.into();
     }
-
-    let result = rocks.len() - rock_count;
-
-    return result.to_string();
 }
 
+aoc_core::examples!();
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = include_str!("../test_input.txt");
-
     #[test]
-    fn test_part_1() {
-        let result = process_part_1(INPUT);
-
-        assert_eq!(result, "24");
-    }
-
-    #[test]
-    fn test_part_2() {
-        let result = process_part_2(INPUT);
-
-        assert_eq!(result, "93");
+    fn test_floor_past_x_zero() {
+        // The floor ends up 602 rows down, but the shelf across the top stops the sand after four grains
+        let cave = Day14::parse("0,2 -> 1000,2\n500,600 -> 500,600\n").unwrap();
+
+        assert_eq!(cave.tiles.width(), 2 * 602 + 1);
+        assert_eq!(cave.source, Point::new(602, 0));
+        assert_eq!(Day14::part_1(&cave), Answer::from(4));
+        assert_eq!(Day14::part_2(&cave), Answer::from(4));
     }
 }
-
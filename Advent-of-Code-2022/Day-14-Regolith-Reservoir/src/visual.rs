@@ -0,0 +1,46 @@
+use aoc_visual::{Color, Recorder, Visualize};
+
+use crate::{Day14, Tile};
+
+fn color(tile: &Tile) -> Color {
+    match tile {
+        Tile::Air => Color::rgb(20, 20, 30),
+        Tile::Rock => Color::rgb(120, 110, 100),
+        Tile::Sand => Color::rgb(230, 190, 90),
+    }
+}
+
+// A frame each time a grain of sand comes to rest
+impl Visualize for Day14 {
+    fn visualize(cave: &Self::Parsed, part: u8, recorder: &mut dyn Recorder) {
+        let mut cave = cave.clone();
+
+        let bottom = if part == 1 {cave.abyss()} else {cave.add_floor()};
+
+        recorder.record(&cave.tiles.map(color));
+
+        cave.pour(bottom, |tiles| recorder.record(&tiles.map(color)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+    use aoc_visual::Frame;
+
+    use super::*;
+
+    #[test]
+    fn test_visualize() {
+        let cave = Day14::parse_input(include_str!("../test_input.txt")).unwrap();
+
+        let mut frames: Vec<Frame> = vec![];
+
+        Day14::visualize(&cave, 2, &mut frames);
+
+        let sand = frames.last().unwrap().iter().filter(|&(_, &pixel)| pixel == color(&Tile::Sand)).count();
+
+        assert_eq!(frames.len(), 1 + 93);
+        assert_eq!(sand, 93);
+    }
+}
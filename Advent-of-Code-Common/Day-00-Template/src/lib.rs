@@ -0,0 +1,21 @@
+use aoc_core::{Answer, ParseError, Solution};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    type Parsed = ();
+
+    fn parse(_input: &str) -> Result<Self::Parsed, ParseError> {
+        todo!("Parse");
+    }
+
+    fn part_1(_parsed: &Self::Parsed) -> Answer {
+        todo!("Part 1");
+    }
+
+    fn part_2(_parsed: &Self::Parsed) -> Answer {
+        todo!("Part 2");
+    }
+}
+
+aoc_core::examples!();
@@ -0,0 +1,19 @@
+// Timing and tables shared by the `aoc` and `euler` runners
+mod stats;
+mod table;
+
+use std::time::Duration;
+
+pub use stats::{measure, Stats};
+pub use table::Table;
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    match nanos {
+        0..=999 => format!("{nanos}ns"),
+        1_000..=999_999 => format!("{:.2}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
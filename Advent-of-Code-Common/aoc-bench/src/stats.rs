@@ -0,0 +1,96 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "std_dev_ns", serialize_with = "as_nanos")]
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let n = samples.len();
+
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+
+        let median = if n.is_multiple_of(2) {(samples[n / 2 - 1] + samples[n / 2]) / 2} else {samples[n / 2]};
+
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>() / n as f64;
+
+        Stats {mean, median, min: samples[0], std_dev: Duration::from_secs_f64(variance.sqrt())}
+    }
+}
+
+// Runs `run` untimed `warmup` times, then times it `iterations` times
+pub fn measure<T>(warmup: usize, iterations: usize, mut run: impl FnMut() -> T) -> Stats {
+    (0..warmup).for_each(|_| {
+        black_box(run());
+    });
+
+    let samples = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+
+            black_box(run());
+
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 2, 9, 5, 5, 4, 7, 4]
+            .into_iter()
+            .map(Duration::from_micros)
+            .collect();
+
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.median, Duration::from_nanos(4500));
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.std_dev.as_nanos(), 2000);
+    }
+
+    #[test]
+    fn test_json() {
+        let stats = Stats::from_samples(vec![Duration::from_nanos(1500)]);
+
+        let json = serde_json::to_string(&stats).unwrap();
+
+        assert_eq!(json, r#"{"mean_ns":1500,"median_ns":1500,"min_ns":1500,"std_dev_ns":0}"#);
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut runs = 0;
+
+        measure(2, 5, || runs += 1);
+
+        assert_eq!(runs, 7);
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<I, S>(headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Table {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push<I, S>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|cell| cell.chars().count())
+                    .chain([header.chars().count()])
+                    .max()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    pub fn markdown(&self) -> String {
+        let row = |cells: &[String]| format!("| {} |", cells.join(" | "));
+
+        let separator: Vec<String> = self.headers
+            .iter()
+            .map(|_| "---".to_string())
+            .collect();
+
+        [row(&self.headers), row(&separator)]
+            .into_iter()
+            .chain(self.rows.iter().map(|cells| row(cells)))
+            .map(|line| line + "\n")
+            .collect()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+
+        let write_row = |f: &mut fmt::Formatter<'_>, row: &[String]| {
+            let line = widths
+                .iter()
+                .enumerate()
+                .map(|(i, width)| format!("{:<width$}", row.get(i).map_or("", |cell| cell.as_str())))
+                .collect::<Vec<_>>()
+                .join("  ");
+
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_row(f, &self.headers)?;
+
+        let separator: Vec<String> = widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect();
+
+        write_row(f, &separator)?;
+
+        for row in &self.rows {
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    Picture(Picture),
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::Text(_) => "text",
+            Answer::Picture(_) => "picture",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(integer) => write!(f, "{integer}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Picture(picture) => write!(f, "{picture}"),
+        }
+    }
+}
+
+macro_rules! integer_answer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(integer: $integer) -> Self {
+                    Answer::Integer(integer as i128)
+                }
+            }
+
+            impl PartialEq<$integer> for Answer {
+                fn eq(&self, other: &$integer) -> bool {
+                    matches!(self, Answer::Integer(integer) if *integer == *other as i128)
+                }
+            }
+        )*
+    };
+}
+
+integer_answer!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Picture> for Answer {
+    fn from(picture: Picture) -> Self {
+        Answer::Picture(picture)
+    }
+}
+
+// Integers never equal text, so a solution that starts returning "42" instead of 42 fails its tests
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Integer(_) => false,
+            Answer::Text(text) => text == other,
+            Answer::Picture(picture) => picture.to_string() == other.trim_end(),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl Picture {
+    pub fn new(width: usize, height: usize) -> Self {
+        Picture {width, height, pixels: vec![false; width * height]}
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, lit: bool) {
+        self.pixels[y * self.width + x] = lit;
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    pub fn from_rows<S: AsRef<str>>(rows: &[S]) -> Self {
+        let height = rows.len();
+        let width = rows
+            .iter()
+            .map(|row| row.as_ref().chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut picture = Picture::new(width, height);
+
+        rows.iter().enumerate().for_each(|(y, row)| {
+            row.as_ref().chars().enumerate().for_each(|(x, c)| picture.set(x, y, c == '#'));
+        });
+
+        picture
+    }
+
+    // Block characters read far better than `#` and `.` when eyeballing letters in a terminal
+    pub fn render(&self, lit: char, unlit: char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(|&pixel| if pixel {lit} else {unlit}).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for Picture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render('#', '.'))
+    }
+}
+
+impl Serialize for Picture {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows: Vec<String> = self.to_string().lines().map(String::from).collect();
+
+        rows.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Picture {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<String>::deserialize(deserializer)?;
+
+        Ok(Picture::from_rows(&rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_equality() {
+        let answer = Answer::from(24000u32);
+
+        assert_eq!(answer, 24000);
+        assert_eq!(answer, 24000usize);
+        assert_ne!(answer, "24000");
+    }
+
+    #[test]
+    fn test_picture_equality() {
+        let answer = Answer::from(Picture::from_rows(&["#.", ".#"]));
+
+        assert_eq!(answer, "#.\n.#\n");
+        assert_eq!(answer.to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let answers = [
+            Answer::from(2713310158u64),
+            Answer::from("CMZ"),
+            Answer::from(Picture::from_rows(&["##..", "..##"])),
+        ];
+
+        let json = serde_json::to_string(&answers).unwrap();
+
+        assert_eq!(json, r###"[{"type":"integer","value":2713310158},{"type":"text","value":"CMZ"},{"type":"picture","value":["##..","..##"]}]"###);
+
+        let round_trip: Vec<Answer> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(round_trip, answers);
+    }
+}
@@ -0,0 +1,136 @@
+use std::error::Error;
+use std::fmt;
+
+const SNIPPET_WIDTH: usize = 60;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    snippet: String,
+    caret: usize,
+    expected: String,
+    found: String,
+}
+
+impl ParseError {
+    pub fn at(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+
+        let line = input[..offset].matches('\n').count() + 1;
+        let column = input[line_start..offset].chars().count() + 1;
+
+        let (snippet, caret) = snippet(input[line_start..line_end].trim_end_matches('\r'), column - 1);
+
+        let rest = &input[offset..];
+
+        let found = match rest.chars().next() {
+            None => "end of input".to_string(),
+            Some('\n' | '\r') => "end of line".to_string(),
+            Some(_) => {
+                let line = rest.lines().next().unwrap_or(rest).trim_end_matches('\r');
+
+                match line.char_indices().nth(20) {
+                    Some((end, _)) => format!("{:?}", format!("{}...", &line[..end])),
+                    None => format!("{line:?}"),
+                }
+            },
+        };
+
+        ParseError {line, column, snippet, caret, expected: expected.into(), found}
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn summary(&self) -> String {
+        format!("line {}, column {}: expected {}, found {}", self.line, self.column, self.expected, self.found)
+    }
+}
+
+// Long lines (Day 6 is a single 4k line) are cut down to a window around the error
+fn snippet(line: &str, column: usize) -> (String, usize) {
+    let chars: Vec<char> = line.chars().collect();
+
+    if chars.len() <= SNIPPET_WIDTH {
+        return (line.to_string(), column);
+    }
+
+    let start = column.saturating_sub(SNIPPET_WIDTH / 2).min(chars.len().saturating_sub(SNIPPET_WIDTH));
+    let end = (start + SNIPPET_WIDTH).min(chars.len());
+
+    let mut snippet: String = chars[start..end].iter().collect();
+    let mut caret = column - start;
+
+    if start > 0 {
+        snippet = format!("...{snippet}");
+        caret += 3;
+    }
+
+    if end < chars.len() {
+        snippet += "...";
+    }
+
+    (snippet, caret)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "{}", self.summary())?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.caret))
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let error = ParseError::at("move 1 from 2 to 1\nmove x from 1 to 3\n", 24, "a number");
+
+        assert_eq!(error.line(), 2);
+        assert_eq!(error.column(), 6);
+        assert_eq!(error.snippet(), "move x from 1 to 3");
+        assert_eq!(error.to_string(), "line 2, column 6: expected a number, found \"x from 1 to 3\"\n  |\n2 | move x from 1 to 3\n  |      ^");
+    }
+
+    #[test]
+    fn test_long_line() {
+        let input = "a".repeat(100) + "1" + &"a".repeat(100);
+
+        let error = ParseError::at(&input, 100, "a letter");
+
+        assert_eq!(error.column(), 101);
+        assert_eq!(error.snippet().chars().nth(error.caret), Some('1'));
+    }
+
+    #[test]
+    fn test_end_of_input() {
+        let error = ParseError::at("[1,2", 4, "\"]\"");
+
+        assert_eq!(error.summary(), "line 1, column 5: expected \"]\", found end of input");
+    }
+}
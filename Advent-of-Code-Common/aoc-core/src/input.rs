@@ -0,0 +1,46 @@
+// Puzzle inputs arrive with whatever line endings and trailing whitespace the editor or download left behind. Every
+// input is normalized before it reaches a parser, so the parsers only ever see `\n` line endings, no whitespace at the
+// end of a line, and exactly one newline at the end of the input.
+pub fn normalize(input: &str) -> String {
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+
+    let length = lines.iter().rposition(|line| !line.is_empty()).map_or(0, |last| last + 1);
+
+    lines[..length].iter().map(|line| format!("{line}\n")).collect()
+}
+
+// The ways a saved input commonly differs from a freshly downloaded one, for testing that a parser copes with them
+pub fn variants(input: &str) -> Vec<(&'static str, String)> {
+    let input = normalize(input);
+
+    let spaced: String = input.lines().map(|line| format!("{line} \t \n")).collect();
+
+    vec![
+        ("as downloaded", input.clone()),
+        ("CRLF line endings", input.replace('\n', "\r\n")),
+        ("no final newline", input.trim_end_matches('\n').to_string()),
+        ("extra blank lines at the end", input.clone() + "\n\n"),
+        ("trailing whitespace", spaced.clone()),
+        ("all of the above", spaced.replace('\n', "\r\n") + "  \r\n\r\n"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1000\r\n2000  \r\n\r\n3000"), "1000\n2000\n\n3000\n");
+        assert_eq!(normalize("    [D]    \n[N] [C]    \n"), "    [D]\n[N] [C]\n");
+        assert_eq!(normalize("noop\n\n\n"), "noop\n");
+        assert_eq!(normalize(" \n\n"), "");
+    }
+
+    #[test]
+    fn test_variants() {
+        variants("R 4\nU 4\n")
+            .into_iter()
+            .for_each(|(variant, input)| assert_eq!(normalize(&input), "R 4\nU 4\n", "{variant}"));
+    }
+}
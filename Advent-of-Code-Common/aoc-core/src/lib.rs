@@ -0,0 +1,20 @@
+mod answer;
+mod error;
+pub mod input;
+pub mod parse;
+mod solution;
+
+pub use answer::{Answer, Picture};
+pub use error::ParseError;
+pub use solution::{DynSolution, Solution};
+
+// Pulls in the tests that aoc-examples generated from the day's examples.toml
+#[macro_export]
+macro_rules! examples {
+    () => {
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
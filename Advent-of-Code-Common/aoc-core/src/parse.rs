@@ -0,0 +1,236 @@
+use std::cmp::Ordering;
+
+use nom::character::complete::newline;
+use nom::error::{ContextError, ErrorKind};
+use nom::multi::many0_count;
+use nom::{InputLength, Parser};
+
+use crate::ParseError;
+
+pub type IResult<I, O> = nom::IResult<I, O, Error<I>>;
+
+// nom error that remembers what the failing parser was looking for, so `finish` can explain it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error<I> {
+    input: I,
+    expected: Vec<String>,
+}
+
+impl<I> Error<I> {
+    pub fn expected(input: I, expected: impl Into<String>) -> Self {
+        Error {input, expected: vec![expected.into()]}
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    let description = match kind {
+        ErrorKind::Digit => "a number",
+        ErrorKind::Alpha => "a letter",
+        ErrorKind::AlphaNumeric => "a letter or digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "a line ending",
+        ErrorKind::Eof => "end of input",
+        ErrorKind::Tag => "a literal",
+        ErrorKind::Char => "a character",
+        ErrorKind::OneOf | ErrorKind::IsA | ErrorKind::Satisfy => "one of the allowed characters",
+        ErrorKind::Many1 | ErrorKind::SeparatedList => "at least one item",
+        ErrorKind::Verify | ErrorKind::MapRes | ErrorKind::MapOpt => "a valid value",
+        kind => return kind.description().to_lowercase(),
+    };
+
+    description.to_string()
+}
+
+impl<I: InputLength> nom::error::ParseError<I> for Error<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Error::expected(input, describe(kind))
+    }
+
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        match c {
+            '\n' => Error::expected(input, "a newline"),
+            c => Error::expected(input, format!("{c:?}")),
+        }
+    }
+
+    // Keep whichever branch of an `alt` got furthest, merging the expectations of branches that failed at the same spot
+    fn or(self, other: Self) -> Self {
+        match self.input.input_len().cmp(&other.input.input_len()) {
+            Ordering::Less => self,
+            Ordering::Greater => other,
+            Ordering::Equal => {
+                let mut expected = self.expected;
+
+                other.expected
+                    .into_iter()
+                    .for_each(|alternative| if !expected.contains(&alternative) {expected.push(alternative)});
+
+                Error {input: other.input, expected}
+            },
+        }
+    }
+}
+
+impl<I: InputLength> ContextError<I> for Error<I> {
+    fn add_context(input: I, context: &'static str, other: Self) -> Self {
+        if input.input_len() == other.input.input_len() {Error::expected(other.input, context)} else {other}
+    }
+}
+
+// `nom::bytes::complete::tag` with an error that names the literal it wanted
+pub fn tag<'a>(literal: &'static str) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> {
+    move |input: &'a str| {
+        nom::bytes::complete::tag(literal)(input)
+            .map_err(|err: nom::Err<Error<&'a str>>| err.map(|_| Error::expected(input, format!("{literal:?}"))))
+    }
+}
+
+// The end of a line. Inputs are normalized before parsing, so this is the only line ending there is to match
+pub fn eol(input: &str) -> IResult<&str, char> {
+    newline(input).map_err(|err: nom::Err<Error<&str>>| err.map(|_| Error::expected(input, "end of line")))
+}
+
+fn cut(err: nom::Err<Error<&str>>) -> nom::Err<Error<&str>> {
+    match err {
+        nom::Err::Error(error) => nom::Err::Failure(error),
+        err => err,
+    }
+}
+
+// One element per line. Unlike `separated_list1(newline, ..)` a line that fails to parse is an error instead of the
+// silent end of the list, so a mangled line in the middle of a file is reported rather than everything after it
+// being ignored. The list ends at a blank line or the end of the input, and each element must use up its line.
+pub fn lines<'a, O, F>(mut element: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (mut input, first) = element.parse(input)?;
+
+        let mut items = vec![first];
+
+        loop {
+            if input.is_empty() {
+                return Ok((input, items));
+            }
+
+            let (rest, _) = eol(input).map_err(cut)?;
+
+            if rest.is_empty() || eol(rest).is_ok() {
+                return Ok((input, items));
+            }
+
+            let (rest, item) = element.parse(rest).map_err(cut)?;
+
+            items.push(item);
+            input = rest;
+        }
+    }
+}
+
+// Blocks of lines separated by one or more blank lines, with the same strictness as `lines`
+pub fn blocks<'a, O, F>(mut element: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (mut input, first) = element.parse(input)?;
+
+        let mut items = vec![first];
+
+        loop {
+            let (rest, line_endings) = many0_count(eol)(input)?;
+
+            if line_endings < 2 || rest.is_empty() {
+                return Ok((input, items));
+            }
+
+            let (rest, item) = element.parse(rest).map_err(cut)?;
+
+            items.push(item);
+            input = rest;
+        }
+    }
+}
+
+// Runs a parser over the whole input, allowing only trailing whitespace to be left over
+pub fn finish<'a, O, F>(input: &'a str, mut parser: F) -> Result<O, ParseError>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    match parser.parse(input) {
+        Ok((rest, output)) => {
+            let trailing = rest.trim_start();
+
+            if trailing.is_empty() {
+                Ok(output)
+            } else {
+                Err(ParseError::at(input, input.len() - trailing.len(), "end of input"))
+            }
+        },
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            Err(ParseError::at(input, input.len() - error.input.len(), error.expected.join(" or ")))
+        },
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(input, input.len(), "more input")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nom::branch::alt;
+    use nom::character::complete;
+    use nom::sequence::separated_pair;
+
+    fn pair(input: &str) -> IResult<&str, (u32, u32)> {
+        separated_pair(complete::u32, tag(" -> "), complete::u32)(input)
+    }
+
+    #[test]
+    fn test_lines() {
+        let result = finish("1 -> 2\n3 -> 4\n", lines(pair));
+
+        assert_eq!(result, Ok(vec![(1, 2), (3, 4)]));
+    }
+
+    #[test]
+    fn test_lines_reports_bad_line() {
+        let error = finish("1 -> 2\n3 => 4\n5 -> 6\n", lines(pair)).unwrap_err();
+
+        assert_eq!(error.summary(), "line 2, column 2: expected \" -> \", found \" => 4\"");
+    }
+
+    #[test]
+    fn test_lines_reports_leftovers() {
+        let error = finish("1 -> 2 -> 3\n", lines(pair)).unwrap_err();
+
+        assert_eq!(error.summary(), "line 1, column 7: expected end of line, found \" -> 3\"");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let result = finish("1 -> 2\n3 -> 4\n\n\n5 -> 6", blocks(lines(pair)));
+
+        assert_eq!(result, Ok(vec![vec![(1, 2), (3, 4)], vec![(5, 6)]]));
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let error = finish("$ pwd", alt((tag("$ cd"), tag("$ ls")))).unwrap_err();
+
+        assert_eq!(error.expected(), "\"$ cd\" or \"$ ls\"");
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let error = finish("1 -> 2\n\ngarbage", lines(pair)).unwrap_err();
+
+        assert_eq!((error.line(), error.column()), (3, 1));
+        assert_eq!(error.expected(), "end of input");
+    }
+}
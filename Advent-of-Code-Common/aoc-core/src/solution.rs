@@ -0,0 +1,83 @@
+use std::any::Any;
+
+use crate::input::normalize;
+use crate::{Answer, ParseError};
+
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    // What runners and tests call, so that `parse` only ever sees a normalized input
+    fn parse_input(input: &str) -> Result<Self::Parsed, ParseError> {
+        Self::parse(&normalize(input))
+    }
+
+    fn part_1(parsed: &Self::Parsed) -> Answer;
+
+    fn part_2(parsed: &Self::Parsed) -> Answer;
+}
+
+// Object safe view of a `Solution` so runners can hold every day in one table
+pub trait DynSolution: Send + Sync {
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    fn part_dyn(&self, parsed: &dyn Any, part: u8) -> Answer;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Send + Sync,
+    S::Parsed: 'static,
+{
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        let parsed = S::parse_input(input)?;
+
+        Ok(Box::new(parsed))
+    }
+
+    fn part_dyn(&self, parsed: &dyn Any, part: u8) -> Answer {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("Parsed input belongs to a different solution");
+
+        match part {
+            1 => S::part_1(parsed),
+            2 => S::part_2(parsed),
+            _ => panic!("Part {part} does not exist"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Parsed = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part_1(lines: &Self::Parsed) -> Answer {
+            lines.len().into()
+        }
+
+        fn part_2(lines: &Self::Parsed) -> Answer {
+            lines.concat().into()
+        }
+    }
+
+    #[test]
+    fn test_dyn_solution() {
+        let solution: &dyn DynSolution = &Lines;
+
+        let parsed = solution.parse_dyn("ab  \r\ncd\n\n").unwrap();
+
+        assert_eq!(solution.part_dyn(parsed.as_ref(), 1), 2);
+        assert_eq!(solution.part_dyn(parsed.as_ref(), 2), "abcd");
+    }
+}
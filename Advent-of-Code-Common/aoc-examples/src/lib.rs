@@ -0,0 +1,213 @@
+// Build script helper that turns a day's `examples.toml` into `#[test]` functions.
+//
+//     [[example]]
+//     input = "test_input.txt"      # or `text = "..."` for short inline examples
+//     part_1 = 13                   # integers and text compare as the matching answer type
+//     part_2 = { file = "test_output.txt" }
+//
+// A day's build.rs calls `aoc_examples::generate()` and its lib.rs pulls the tests in with `aoc_core::examples!()`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+const EXAMPLES: &str = "examples.toml";
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Integer(i64),
+    Text(String),
+    File {file: String},
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Example {
+    name: Option<String>,
+    input: Option<String>,
+    text: Option<String>,
+    part_1: Option<Expected>,
+    part_2: Option<Expected>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Examples {
+    #[serde(default)]
+    example: Vec<Example>,
+}
+
+// "day-09" -> "Day09", and "day-2023-05" -> "Day05" for the years after 2022 that have it in their package name
+fn solution_name(package: &str) -> String {
+    format!("Day{}", package.rsplit('-').next().unwrap_or_default())
+}
+
+fn test_name(example: &Example, i: usize) -> String {
+    match &example.name {
+        Some(name) => name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() {c.to_ascii_lowercase()} else {'_'})
+            .collect(),
+        None => format!("example_{}", i + 1),
+    }
+}
+
+// Everything read at test time is included by absolute path, so the tests pass from any working directory
+fn include(directory: &Path, file: &str) -> String {
+    format!("include_str!({:?})", directory.join(file).display().to_string())
+}
+
+pub fn generate_tests(examples: &str, directory: &Path, solution: &str) -> Result<String, String> {
+    let examples: Examples = toml::from_str(examples).map_err(|err| format!("{EXAMPLES}: {err}"))?;
+
+    let mut tests = String::new();
+
+    for (i, example) in examples.example.iter().enumerate() {
+        let name = test_name(example, i);
+
+        let input = match (&example.input, &example.text) {
+            (Some(file), None) => include(directory, file),
+            (None, Some(text)) => format!("{text:?}"),
+            _ => return Err(format!("{EXAMPLES}: {name} needs exactly one of `input` or `text`")),
+        };
+
+        if example.part_1.is_none() && example.part_2.is_none() {
+            return Err(format!("{EXAMPLES}: {name} has no expected answer for either part"));
+        }
+
+        let expected: Vec<(u8, String)> = [(1, &example.part_1), (2, &example.part_2)]
+            .into_iter()
+            .filter_map(|(part, expected)| {
+                let expected = match expected.as_ref()? {
+                    Expected::Integer(integer) => format!("{integer}i128"),
+                    Expected::Text(text) => format!("{text:?}"),
+                    Expected::File {file} => include(directory, file),
+                };
+
+                Some((part, expected))
+            })
+            .collect();
+
+        for (part, expected) in &expected {
+            writeln!(tests, "#[test]").unwrap();
+            writeln!(tests, "fn {name}_part_{part}() {{").unwrap();
+            writeln!(tests, "    let parsed = <crate::{solution} as aoc_core::Solution>::parse_input({input}).unwrap();").unwrap();
+            writeln!(tests).unwrap();
+            writeln!(tests, "    assert_eq!(<crate::{solution} as aoc_core::Solution>::part_{part}(&parsed), {expected});").unwrap();
+            writeln!(tests, "}}").unwrap();
+            writeln!(tests).unwrap();
+        }
+
+        // The same example saved with CRLF line endings, trailing whitespace and so on has to give the same answers
+        writeln!(tests, "#[test]").unwrap();
+        writeln!(tests, "fn {name}_variants() {{").unwrap();
+        writeln!(tests, "    for (variant, input) in aoc_core::input::variants({input}) {{").unwrap();
+        writeln!(tests, "        let parsed = <crate::{solution} as aoc_core::Solution>::parse_input(&input).unwrap_or_else(|err| panic!(\"{{variant}}: {{err}}\"));").unwrap();
+
+        for (part, expected) in &expected {
+            writeln!(tests).unwrap();
+            writeln!(tests, "        assert_eq!(<crate::{solution} as aoc_core::Solution>::part_{part}(&parsed), {expected}, \"{{variant}}\");").unwrap();
+        }
+
+        writeln!(tests, "    }}").unwrap();
+        writeln!(tests, "}}").unwrap();
+        writeln!(tests).unwrap();
+    }
+
+    Ok(tests)
+}
+
+// The parts with an expected answer in some example, which is how far a day has got. The template's placeholder answers
+// are empty strings, so they do not count
+pub fn solved_parts(examples: &str) -> Result<Vec<u8>, String> {
+    let examples: Examples = toml::from_str(examples).map_err(|err| format!("{EXAMPLES}: {err}"))?;
+
+    let solved = |expected: &Option<Expected>| match expected {
+        None => false,
+        Some(Expected::Text(text)) => !text.is_empty(),
+        Some(_) => true,
+    };
+
+    let parts = [1, 2]
+        .into_iter()
+        .filter(|&part| examples.example.iter().any(|example| solved(if part == 1 {&example.part_1} else {&example.part_2})))
+        .collect();
+
+    Ok(parts)
+}
+
+pub fn generate() {
+    let directory = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let directory = Path::new(&directory);
+
+    let path = directory.join(EXAMPLES);
+
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let examples = fs::read_to_string(&path).unwrap_or_default();
+
+    let solution = solution_name(&env::var("CARGO_PKG_NAME").unwrap());
+
+    let tests = generate_tests(&examples, directory, &solution).unwrap_or_else(|err| panic!("{err}"));
+
+    fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs"), tests).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_tests() {
+        let examples = r#"
+            [[example]]
+            input = "test_input_1.txt"
+            part_1 = 13
+
+            [[example]]
+            name = "Larger rope"
+            text = "R 5\nU 8\n"
+            part_2 = "36"
+        "#;
+
+        let tests = generate_tests(examples, Path::new("/days/Day-09"), &solution_name("day-09")).unwrap();
+
+        assert!(tests.contains("fn example_1_part_1() {\n    let parsed = <crate::Day09 as aoc_core::Solution>::parse_input(include_str!(\"/days/Day-09/test_input_1.txt\")).unwrap();"));
+        assert!(tests.contains("::part_1(&parsed), 13i128);"));
+        assert!(tests.contains("fn larger_rope_part_2() {\n    let parsed = <crate::Day09 as aoc_core::Solution>::parse_input(\"R 5\\nU 8\\n\").unwrap();"));
+        assert!(tests.contains("::part_2(&parsed), \"36\");"));
+        assert!(!tests.contains("example_1_part_2"));
+        assert!(tests.contains("fn larger_rope_variants() {\n    for (variant, input) in aoc_core::input::variants(\"R 5\\nU 8\\n\") {"));
+        assert!(tests.contains("::part_2(&parsed), \"36\", \"{variant}\");"));
+    }
+
+    #[test]
+    fn test_solution_name() {
+        assert_eq!(solution_name("day-09"), "Day09");
+        assert_eq!(solution_name("day-2023-05"), "Day05");
+    }
+
+    #[test]
+    fn test_solved_parts() {
+        let template = "[[example]]\ninput = \"test_input.txt\"\npart_1 = \"\"\npart_2 = \"\"\n";
+        let halfway = "[[example]]\ninput = \"test_input.txt\"\npart_1 = 13\npart_2 = \"\"\n";
+
+        assert_eq!(solved_parts(template), Ok(vec![]));
+        assert_eq!(solved_parts(halfway), Ok(vec![1]));
+        assert_eq!(solved_parts(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_invalid_examples() {
+        let both = "[[example]]\ninput = \"a.txt\"\ntext = \"a\"\npart_1 = 1\n";
+        let no_answer = "[[example]]\ninput = \"a.txt\"\n";
+
+        assert_eq!(generate_tests(both, Path::new("/"), "Day01"), Err("examples.toml: example_1 needs exactly one of `input` or `text`".to_string()));
+        assert_eq!(generate_tests(no_answer, Path::new("/"), "Day01"), Err("examples.toml: example_1 has no expected answer for either part".to_string()));
+        assert!(generate_tests("[[example]]\nimput = \"a.txt\"\n", Path::new("/"), "Day01").is_err());
+    }
+}
@@ -0,0 +1,145 @@
+// Differential testing for the days: random inputs from a seed, checked against slow but obviously correct solvers.
+//
+//     #[test]
+//     fn test_against_reference() {
+//         aoc_fuzz::differential::<Day09>(1000);
+//     }
+//
+// `AOC_FUZZ_SEEDS=100000` runs more seeds than a day asks for, and `AOC_FUZZ_SEED=1234` replays a single failing one.
+
+use std::any::Any;
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use aoc_core::{Answer, Solution};
+
+pub use rand;
+
+pub const SEEDS_ENV: &str = "AOC_FUZZ_SEEDS";
+pub const SEED_ENV: &str = "AOC_FUZZ_SEED";
+
+pub trait Fuzz: Solution {
+    // A valid puzzle input, the same one for the same seed
+    fn generate(rng: &mut StdRng) -> String;
+
+    // The references read the raw input themselves, so they share no parsing with the solution they check
+    fn reference_1(input: &str) -> Answer;
+
+    fn reference_2(input: &str) -> Answer;
+}
+
+pub fn generate<S: Fuzz>(seed: u64) -> String {
+    S::generate(&mut StdRng::seed_from_u64(seed))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "panicked".to_string(),
+    }
+}
+
+// Runs the solution and the reference on the input for `seed`, describing the first disagreement
+pub fn check<S: Fuzz>(seed: u64) -> Result<(), String> {
+    let input = generate::<S>(seed);
+
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        let parsed = S::parse_input(&input).map_err(|err| format!("the input does not parse at {err}"))?;
+
+        let answers = [(S::part_1(&parsed), S::reference_1(&input)), (S::part_2(&parsed), S::reference_2(&input))];
+
+        for (part, (answer, expected)) in (1..).zip(answers) {
+            if answer != expected {
+                return Err(format!("part {part} gave {answer} but the reference gave {expected}"));
+            }
+        }
+
+        Ok(())
+    }));
+
+    outcome
+        .unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(payload))))
+        .map_err(|err| format!("Seed {seed}: {err}\n\n{input}"))
+}
+
+pub fn differential<S: Fuzz>(seeds: u64) {
+    let (first, count) = match (env::var(SEED_ENV), env::var(SEEDS_ENV)) {
+        (Ok(seed), _) => (seed.parse().expect("AOC_FUZZ_SEED is not a number"), 1),
+        (_, Ok(seeds)) => (0, seeds.parse().expect("AOC_FUZZ_SEEDS is not a number")),
+        _ => (0, seeds),
+    };
+
+    if let Some(failure) = (first..first + count).find_map(|seed| check::<S>(seed).err()) {
+        panic!("{failure}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::Rng;
+
+    use aoc_core::ParseError;
+
+    // Sums a list of numbers, with a part 2 that goes wrong once the list gets long
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            Ok(input.trim().split(',').map(|number| number.parse().unwrap()).collect())
+        }
+
+        fn part_1(numbers: &Self::Parsed) -> Answer {
+            numbers.iter().sum::<u32>().into()
+        }
+
+        fn part_2(numbers: &Self::Parsed) -> Answer {
+            numbers.iter().take(5).sum::<u32>().into()
+        }
+    }
+
+    impl Fuzz for Sum {
+        fn generate(rng: &mut StdRng) -> String {
+            let length = rng.gen_range(1..=6);
+
+            (0..length).map(|_| rng.gen_range(0..100).to_string()).collect::<Vec<String>>().join(",")
+        }
+
+        fn reference_1(input: &str) -> Answer {
+            input.split(',').map(|number| number.parse::<u32>().unwrap()).sum::<u32>().into()
+        }
+
+        fn reference_2(input: &str) -> Answer {
+            Sum::reference_1(input)
+        }
+    }
+
+    #[test]
+    fn test_generate_is_seeded() {
+        assert_eq!(generate::<Sum>(7), generate::<Sum>(7));
+        assert_ne!(generate::<Sum>(7), generate::<Sum>(8));
+    }
+
+    #[test]
+    fn test_check() {
+        let seed = (0..)
+            .find(|&seed| {
+                let input = generate::<Sum>(seed);
+
+                input.split(',').count() == 6 && !input.ends_with(",0")
+            })
+            .unwrap();
+
+        let failure = check::<Sum>(seed).unwrap_err();
+
+        assert!(failure.starts_with(&format!("Seed {seed}: part 2 gave ")));
+        assert!(failure.ends_with(&generate::<Sum>(seed)));
+    }
+}
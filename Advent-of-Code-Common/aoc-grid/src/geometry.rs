@@ -0,0 +1,283 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point {x, y}
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// Screen orientation, like the puzzle maps: y grows downwards, so `Up` is a step towards y = 0
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    // Accepts both the `R/U/L/D` letters and the `^>v<` arrows used across the puzzles
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Direction::Up),
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+// Inclusive on both ends, so a box around a single point is 1x1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundingBox<T> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Copy + Ord> BoundingBox<T> {
+    pub fn new(point: Point<T>) -> Self {
+        BoundingBox {min: point, max: point}
+    }
+
+    pub fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+
+        let mut bounding_box = BoundingBox::new(points.next()?);
+
+        points.for_each(|point| bounding_box.include(point));
+
+        Some(bounding_box)
+    }
+
+    pub fn include(&mut self, point: Point<T>) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+macro_rules! integer_point {
+    ($($integer:ty => $unsigned:ty),*) => {
+        $(
+            impl Point<$integer> {
+                pub fn manhattan(self, other: Self) -> $unsigned {
+                    self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+                }
+
+                pub fn chebyshev(self, other: Self) -> $unsigned {
+                    self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+                }
+            }
+
+            impl BoundingBox<$integer> {
+                pub fn width(&self) -> $unsigned {
+                    self.max.x.abs_diff(self.min.x) + 1
+                }
+
+                pub fn height(&self) -> $unsigned {
+                    self.max.y.abs_diff(self.min.y) + 1
+                }
+            }
+        )*
+    };
+}
+
+integer_point!(i32 => u32, i64 => u64, isize => usize, u32 => u32, u64 => u64, usize => usize);
+
+macro_rules! signed_point {
+    ($($integer:ty),*) => {
+        $(
+            impl Point<$integer> {
+                pub fn signum(self) -> Self {
+                    Point::new(self.x.signum(), self.y.signum())
+                }
+
+                // At most one step along each axis, the way a rope knot follows the knot in front of it
+                pub fn step_toward(self, target: Self) -> Self {
+                    self + (target - self).signum()
+                }
+            }
+
+            impl From<Direction> for Point<$integer> {
+                fn from(direction: Direction) -> Self {
+                    match direction {
+                        Direction::Up => Point::new(0, -1),
+                        Direction::Right => Point::new(1, 0),
+                        Direction::Down => Point::new(0, 1),
+                        Direction::Left => Point::new(-1, 0),
+                    }
+                }
+            }
+
+            impl Add<Direction> for Point<$integer> {
+                type Output = Self;
+
+                fn add(self, direction: Direction) -> Self {
+                    self + Point::from(direction)
+                }
+            }
+
+            impl AddAssign<Direction> for Point<$integer> {
+                fn add_assign(&mut self, direction: Direction) {
+                    *self = *self + direction;
+                }
+            }
+        )*
+    };
+}
+
+signed_point!(i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a * 2, Point::new(6, -4));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a + Direction::Up, Point::new(3, -3));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1i32, 1);
+        let b = Point::new(4, -1);
+
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!(Point::new(2usize, 7).manhattan(Point::new(5, 3)), 7);
+    }
+
+    #[test]
+    fn test_step_toward() {
+        let tail = Point::new(0i32, 0);
+
+        assert_eq!(tail.step_toward(Point::new(2, 0)), Point::new(1, 0));
+        assert_eq!(tail.step_toward(Point::new(2, -1)), Point::new(1, -1));
+        assert_eq!(tail.step_toward(tail), tail);
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!("RULD^>v<".chars().map(Direction::from_char).collect::<Option<Vec<_>>>(), Some(vec![
+            Direction::Right, Direction::Up, Direction::Left, Direction::Down,
+            Direction::Up, Direction::Right, Direction::Down, Direction::Left,
+        ]));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let bounding_box = BoundingBox::from_points([Point::new(498u32, 4), Point::new(503, 9), Point::new(494, 6)]).unwrap();
+
+        assert_eq!(bounding_box.min, Point::new(494, 4));
+        assert_eq!(bounding_box.max, Point::new(503, 9));
+        assert_eq!((bounding_box.width(), bounding_box.height()), (10, 6));
+        assert!(bounding_box.contains(Point::new(500, 4)));
+        assert!(!bounding_box.contains(Point::new(500, 10)));
+        assert_eq!(BoundingBox::<i32>::from_points([]), None);
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use aoc_core::ParseError;
+
+use crate::geometry::{Direction, Point};
+
+pub type Position = Point<usize>;
+
+pub const ADJACENT: [Point<isize>; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+];
+
+// Row-major grid addressed by (x, y), with (0, 0) in the top left corner
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "a {width}x{height} grid needs {} cells", width * height);
+
+        Grid {width, height, cells}
+    }
+
+    // One row per line, each character turned into a cell. Rows must all be the same width.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let rows = input.trim_end_matches(['\n', '\r']);
+
+        if rows.is_empty() {
+            return Err(ParseError::at(input, 0, "a grid"));
+        }
+
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        let mut offset = 0;
+
+        for line in rows.split('\n') {
+            let row = line.trim_end_matches('\r');
+            let mut row_width = 0;
+
+            for (i, c) in row.char_indices() {
+                if width == Some(row_width) {
+                    return Err(ParseError::at(input, offset + i, "end of line"));
+                }
+
+                cells.push(cell(c).ok_or_else(|| ParseError::at(input, offset + i, "a grid cell"))?);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if row_width < width => return Err(ParseError::at(input, offset + row.len(), format!("a row {width} cells wide"))),
+                Some(_) => (),
+            }
+
+            height += 1;
+            offset += line.len() + 1;
+        }
+
+        Ok(Grid {width: width.unwrap(), height, cells})
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, Point {x, y}: Position) -> Option<&T> {
+        if x < self.width && y < self.height {Some(&self.cells[y * self.width + x])} else {None}
+    }
+
+    pub fn get_mut(&mut self, Point {x, y}: Position) -> Option<&mut T> {
+        if x < self.width && y < self.height {Some(&mut self.cells[y * self.width + x])} else {None}
+    }
+
+    // The position one step away, or None when that step leaves the grid
+    pub fn step(&self, position: Position, delta: impl Into<Point<isize>>) -> Option<Position> {
+        let delta = delta.into();
+
+        let x = position.x.checked_add_signed(delta.x)?;
+        let y = position.y.checked_add_signed(delta.y)?;
+
+        if x < self.width && y < self.height {Some(Point::new(x, y))} else {None}
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |i| Point::new(i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.step(position, direction))
+    }
+
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ADJACENT.into_iter().filter_map(move |delta| self.step(position, delta))
+    }
+
+    // Every position from `start` (exclusive) towards the edge of the grid
+    pub fn ray(&self, start: Position, delta: impl Into<Point<isize>>) -> impl Iterator<Item = Position> + '_ {
+        let delta = delta.into();
+        let mut current = Some(start);
+
+        std::iter::from_fn(move || {
+            current = current.and_then(|position| self.step(position, delta));
+
+            current
+        })
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        assert!(y < self.height, "row {y} is outside of a {}x{} grid", self.width, self.height);
+
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside of a {}x{} grid", self.width, self.height);
+
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    // Sliced by row number rather than chunked by width, so a grid with no columns still has all of its (empty) rows
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter().find_map(|(position, cell)| if predicate(cell) {Some(position)} else {None})
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {width: self.width, height: self.height, cells: self.cells.iter().map(f).collect()}
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid {width, height, cells: vec![value; width * height]}
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| panic!("{position} is outside of a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(position).unwrap_or_else(|| panic!("{position} is outside of a {width}x{height} grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.rows().enumerate().try_for_each(|(y, row)| {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            row.iter().try_for_each(|cell| write!(f, "{cell}"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "30373\n25512\n65332\n33549\n35390\n";
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits(INPUT).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (5, 5));
+        assert_eq!(grid[Point::new(3, 1)], 1);
+        assert_eq!(grid.column(4).copied().collect::<Vec<_>>(), [3, 2, 2, 9, 0]);
+        assert_eq!(format!("{grid}\n"), INPUT);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(digits("123\n4x6\n").unwrap_err().summary(), "line 2, column 2: expected a grid cell, found \"x6\"");
+        assert_eq!(digits("123\n45\n").unwrap_err().summary(), "line 2, column 3: expected a row 3 cells wide, found end of line");
+        assert_eq!(digits("123\n4567\n").unwrap_err().summary(), "line 2, column 4: expected end of line, found \"7\"");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits(INPUT).unwrap();
+
+        assert_eq!(grid.neighbours_4(Point::new(0, 0)).collect::<Vec<_>>(), [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours_8(Point::new(4, 4)).collect::<Vec<_>>(), [Point::new(3, 3), Point::new(4, 3), Point::new(3, 4)]);
+        assert_eq!(grid.neighbours_8(Point::new(2, 2)).count(), 8);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = digits(INPUT).unwrap();
+
+        assert_eq!(grid.ray(Point::new(2, 3), Direction::Up).map(|position| grid[position]).collect::<Vec<_>>(), [3, 5, 3]);
+        assert_eq!(grid.ray(Point::new(0, 0), Direction::Left).count(), 0);
+        assert_eq!(grid.ray(Point::new(0, 0), Point::new(1, 1)).count(), 4);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse("Sab\nbcE\n", Some).unwrap();
+
+        assert_eq!(grid.find(|&c| c == 'S'), Some(Point::new(0, 0)));
+        assert_eq!(grid.find(|&c| c == 'E'), Some(Point::new(2, 1)));
+        assert_eq!(grid.find(|&c| c == 'z'), None);
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = Grid::filled(0, 0, 0);
+
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.to_string(), "");
+
+        let grid: Grid<u32> = Grid::from_vec(0, 3, vec![]);
+
+        assert_eq!(grid.rows().count(), grid.height());
+        assert!(grid.rows().all(|row| row.is_empty()));
+        assert_eq!(grid.to_string(), "\n\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "column 7 is outside of a 5x5 grid")]
+    fn test_column_out_of_bounds() {
+        digits(INPUT).unwrap().column(7).count();
+    }
+}
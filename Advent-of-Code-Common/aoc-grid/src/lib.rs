@@ -0,0 +1,5 @@
+mod geometry;
+mod grid;
+
+pub use geometry::{BoundingBox, Direction, Point};
+pub use grid::{Grid, Position, ADJACENT};
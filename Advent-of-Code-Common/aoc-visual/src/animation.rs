@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use gif::{Encoder, Repeat};
+
+use crate::{scale, Color, Frame, Recorder};
+
+// Encodes frames into a looping GIF as they arrive, so a long simulation never has to be held in memory. The encoder
+// needs the picture size up front, so it only starts with the first frame
+pub struct Animation<W: Write> {
+    out: Option<W>,
+    encoder: Option<Encoder<W>>,
+    delay: u16,
+    scale: usize,
+    error: Option<io::Error>,
+}
+
+fn gif_error(err: gif::EncodingError) -> io::Error {
+    match err {
+        gif::EncodingError::Io(err) => err,
+        err => io::Error::other(err),
+    }
+}
+
+// Each frame gets its own palette of the colours it uses, which GIF limits to 256
+fn indexed(frame: &Frame) -> io::Result<(Vec<u8>, Vec<u8>)> {
+    let mut palette: HashMap<Color, u8> = HashMap::new();
+
+    let pixels = frame
+        .iter()
+        .map(|(_, &color)| {
+            let next = palette.len();
+
+            match palette.get(&color) {
+                Some(&index) => Ok(index),
+                None if next < 256 => {
+                    palette.insert(color, next as u8);
+
+                    Ok(next as u8)
+                },
+                None => Err(io::Error::other("a GIF frame can only use 256 colours")),
+            }
+        })
+        .collect::<io::Result<Vec<u8>>>()?;
+
+    let mut colors: Vec<(Color, u8)> = palette.into_iter().collect();
+    colors.sort_by_key(|&(_, index)| index);
+
+    Ok((colors.iter().flat_map(|(color, _)| color.bytes()).collect(), pixels))
+}
+
+impl<W: Write> Animation<W> {
+    pub fn new(out: W, fps: u32, scale: usize) -> Self {
+        // GIF delays are in hundredths of a second
+        let delay = (100 / fps.max(1)).max(1) as u16;
+
+        Animation {out: Some(out), encoder: None, delay, scale, error: None}
+    }
+
+    fn encode(&mut self, frame: &Frame) -> io::Result<()> {
+        let frame = scale(frame, self.scale);
+
+        let (width, height) = match (u16::try_from(frame.width()), u16::try_from(frame.height())) {
+            (Ok(width), Ok(height)) => (width, height),
+            _ => return Err(io::Error::other("GIF frames can be at most 65535 pixels across")),
+        };
+
+        if let Some(out) = self.out.take() {
+            let mut encoder = Encoder::new(out, width, height, &[]).map_err(gif_error)?;
+
+            encoder.set_repeat(Repeat::Infinite).map_err(gif_error)?;
+
+            self.encoder = Some(encoder);
+        }
+
+        let (palette, pixels) = indexed(&frame)?;
+
+        let mut gif_frame = gif::Frame::from_palette_pixels(width, height, pixels, palette, None);
+        gif_frame.delay = self.delay;
+
+        self.encoder.as_mut().unwrap().write_frame(&gif_frame).map_err(gif_error)
+    }
+
+    // Writes the end of the file and hands back the writer, or reports the first error any frame ran into
+    pub fn finish(self) -> io::Result<W> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        match self.encoder {
+            Some(encoder) => encoder.into_inner(),
+            None => Err(io::Error::other("there were no frames to animate")),
+        }
+    }
+}
+
+impl<W: Write> Recorder for Animation<W> {
+    fn record(&mut self, frame: &Frame) {
+        if self.error.is_some() {return}
+
+        if let Err(err) = self.encode(frame) {
+            self.error = Some(err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_animation() {
+        let mut out = vec![];
+
+        let mut animation = Animation::new(&mut out, 25, 2);
+
+        (0..3u8).for_each(|shade| animation.record(&Frame::filled(3, 2, Color::rgb(shade, 0, 0))));
+        animation.finish().unwrap();
+
+        let mut decoder = gif::DecodeOptions::new();
+        decoder.set_color_output(gif::ColorOutput::RGBA);
+
+        let mut decoder = decoder.read_info(out.as_slice()).unwrap();
+
+        assert_eq!((decoder.width(), decoder.height()), (6, 4));
+
+        let mut frames = 0;
+
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 4);
+            assert_eq!(frame.buffer[..4], [frames, 0, 0, 255]);
+
+            frames += 1;
+        }
+
+        assert_eq!(frames, 3);
+    }
+
+    #[test]
+    fn test_palette_limit() {
+        let colors = (0..257).map(|i| Color::rgb((i % 256) as u8, (i / 256) as u8, 0)).collect();
+
+        assert!(indexed(&Frame::from_vec(257, 1, colors)).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color {r, g, b}
+    }
+
+    // The colour `t` of the way from `self` to `other`, with `t` clamped to 0..=1
+    pub fn lerp(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Color::rgb(channel(self.r, other.r), channel(self.g, other.g), channel(self.b, other.b))
+    }
+
+    pub fn bytes(self) -> [u8; 3] {
+        [self.r, self.g, self.b]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp() {
+        let red = Color::rgb(200, 0, 0);
+
+        assert_eq!(Color::BLACK.lerp(red, 0.0), Color::BLACK);
+        assert_eq!(Color::BLACK.lerp(red, 0.5), Color::rgb(100, 0, 0));
+        assert_eq!(Color::BLACK.lerp(red, 2.0), red);
+    }
+}
@@ -0,0 +1,84 @@
+use std::io::{self, Write};
+
+use aoc_grid::Point;
+
+use crate::Frame;
+
+// Blows every cell up into a `factor` by `factor` square, since a cell per pixel is too small to see
+pub fn scale(frame: &Frame, factor: usize) -> Frame {
+    let factor = factor.max(1);
+
+    let cells = (0..frame.height() * factor)
+        .flat_map(|y| (0..frame.width() * factor).map(move |x| frame[Point::new(x / factor, y / factor)]))
+        .collect();
+
+    Frame::from_vec(frame.width() * factor, frame.height() * factor, cells)
+}
+
+fn rgb(frame: &Frame) -> Vec<u8> {
+    frame.iter().flat_map(|(_, color)| color.bytes()).collect()
+}
+
+// Binary PPM, which needs no encoder and which most image viewers open
+pub fn write_ppm(mut out: impl Write, frame: &Frame) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", frame.width(), frame.height())?;
+
+    out.write_all(&rgb(frame))
+}
+
+pub fn write_png(out: impl Write, frame: &Frame) -> io::Result<()> {
+    let mut encoder = png::Encoder::new(out, frame.width() as u32, frame.height() as u32);
+
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+
+    writer.write_image_data(&rgb(frame))?;
+
+    writer.finish()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Color;
+
+    fn frame() -> Frame {
+        Frame::from_vec(2, 1, vec![Color::rgb(255, 0, 0), Color::rgb(0, 0, 255)])
+    }
+
+    #[test]
+    fn test_scale() {
+        let scaled = scale(&frame(), 2);
+
+        assert_eq!((scaled.width(), scaled.height()), (4, 2));
+        assert_eq!(scaled.row(1).copied().collect::<Vec<Color>>(), [frame()[Point::new(0, 0)], frame()[Point::new(0, 0)], frame()[Point::new(1, 0)], frame()[Point::new(1, 0)]]);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut out = vec![];
+
+        write_ppm(&mut out, &frame()).unwrap();
+
+        assert_eq!(out, b"P6\n2 1\n255\n\xff\x00\x00\x00\x00\xff");
+    }
+
+    #[test]
+    fn test_png() {
+        let mut out = vec![];
+
+        write_png(&mut out, &frame()).unwrap();
+
+        let mut reader = png::Decoder::new(out.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+
+        assert_eq!((info.width, info.height), (2, 1));
+        assert_eq!(pixels, [255, 0, 0, 0, 0, 255]);
+    }
+}
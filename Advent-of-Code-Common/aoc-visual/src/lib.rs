@@ -0,0 +1,25 @@
+// Rendering for the days whose puzzles are simulations. A day implements `Visualize` and hands a frame per step to a
+// `Recorder`, which plays them in the terminal (`Terminal`), keeps the last one for a PPM or PNG image (`Still`), or
+// encodes all of them into an animated GIF (`Animation`). `Sample` sits in front of any of them to thin out long runs.
+mod animation;
+mod color;
+mod image;
+mod recorder;
+mod terminal;
+
+use aoc_core::Solution;
+use aoc_grid::Grid;
+
+pub use animation::Animation;
+pub use color::Color;
+pub use image::{scale, write_png, write_ppm};
+pub use recorder::{Recorder, Sample, Still};
+pub use terminal::Terminal;
+
+// One colour per cell of whatever the day is simulating, scaled up to pixels when it is written to an image
+pub type Frame = Grid<Color>;
+
+pub trait Visualize: Solution {
+    // Emits a frame for every step of the simulation behind `part`
+    fn visualize(parsed: &Self::Parsed, part: u8, recorder: &mut dyn Recorder);
+}
@@ -0,0 +1,101 @@
+use crate::Frame;
+
+pub trait Recorder {
+    fn record(&mut self, frame: &Frame);
+}
+
+impl Recorder for Vec<Frame> {
+    fn record(&mut self, frame: &Frame) {
+        self.push(frame.clone());
+    }
+}
+
+// Keeps only the latest frame, for writing out a still image once the simulation is over
+#[derive(Default)]
+pub struct Still {
+    frame: Option<Frame>,
+}
+
+impl Still {
+    pub fn new() -> Self {
+        Still::default()
+    }
+
+    pub fn last(self) -> Option<Frame> {
+        self.frame
+    }
+}
+
+impl Recorder for Still {
+    fn record(&mut self, frame: &Frame) {
+        self.frame = Some(frame.clone());
+    }
+}
+
+// Passes on every `every`th frame. The frame the simulation ends on is held back rather than dropped, so that `finish`
+// can pass it on too and the result always shows the final state
+pub struct Sample<'a> {
+    recorder: &'a mut dyn Recorder,
+    every: usize,
+    seen: usize,
+    skipped: Option<Frame>,
+}
+
+impl<'a> Sample<'a> {
+    pub fn new(recorder: &'a mut dyn Recorder, every: usize) -> Self {
+        Sample {recorder, every: every.max(1), seen: 0, skipped: None}
+    }
+
+    pub fn finish(self) {
+        if let Some(frame) = self.skipped {
+            self.recorder.record(&frame);
+        }
+    }
+}
+
+impl Recorder for Sample<'_> {
+    fn record(&mut self, frame: &Frame) {
+        if self.seen.is_multiple_of(self.every) {
+            self.recorder.record(frame);
+            self.skipped = None;
+        } else {
+            self.skipped = Some(frame.clone());
+        }
+
+        self.seen += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Color;
+
+    fn frame(shade: u8) -> Frame {
+        Frame::filled(1, 1, Color::rgb(shade, shade, shade))
+    }
+
+    fn shades(frames: &[Frame]) -> Vec<u8> {
+        frames.iter().map(|frame| frame.iter().next().unwrap().1.r).collect()
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut frames = vec![];
+
+        let mut sample = Sample::new(&mut frames, 3);
+        (0..8).for_each(|shade| sample.record(&frame(shade)));
+        sample.finish();
+
+        assert_eq!(shades(&frames), [0, 3, 6, 7]);
+
+        let mut frames = vec![];
+
+        let mut sample = Sample::new(&mut frames, 3);
+        (0..7).for_each(|shade| sample.record(&frame(shade)));
+        sample.finish();
+
+        assert_eq!(shades(&frames), [0, 3, 6]);
+    }
+}
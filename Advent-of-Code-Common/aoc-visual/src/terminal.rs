@@ -0,0 +1,87 @@
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use aoc_grid::Point;
+
+use crate::{Color, Frame, Recorder};
+
+// Plays frames in place on an ANSI terminal with 24-bit colour. Each character cell shows two rows of the frame, the
+// upper one as the foreground of a `▀` and the lower one as its background, so the picture keeps square proportions
+pub struct Terminal<W: Write> {
+    out: W,
+    delay: Duration,
+    started: bool,
+    error: Option<io::Error>,
+}
+
+fn ansi(color: Color, layer: u8) -> String {
+    format!("\x1b[{layer}8;2;{};{};{}m", color.r, color.g, color.b)
+}
+
+fn render(frame: &Frame) -> String {
+    let mut screen = String::new();
+
+    (0..frame.height()).step_by(2).for_each(|y| {
+        (0..frame.width()).for_each(|x| {
+            let lower = frame.get(Point::new(x, y + 1)).map_or("\x1b[49m".to_string(), |&color| ansi(color, 4));
+
+            write!(screen, "{}{lower}▀", ansi(frame[Point::new(x, y)], 3)).unwrap();
+        });
+
+        screen.push_str("\x1b[0m\n");
+    });
+
+    screen
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        Terminal {out, delay: Duration::from_secs(1) / fps.max(1), started: false, error: None}
+    }
+
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        // Clear the screen once, then go back to the top left corner so every frame overwrites the one before it
+        if !self.started {
+            write!(self.out, "\x1b[2J")?;
+            self.started = true;
+        }
+
+        write!(self.out, "\x1b[H{}", render(frame))?;
+
+        self.out.flush()
+    }
+
+    pub fn finish(self) -> io::Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+}
+
+impl<W: Write> Recorder for Terminal<W> {
+    fn record(&mut self, frame: &Frame) {
+        if self.error.is_some() {return}
+
+        match self.draw(frame) {
+            Ok(()) => thread::sleep(self.delay),
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let frame = Frame::from_vec(1, 3, vec![Color::rgb(255, 0, 0), Color::rgb(0, 0, 255), Color::WHITE]);
+
+        let expected = [
+            "\x1b[38;2;255;0;0m\x1b[48;2;0;0;255m▀\x1b[0m\n",
+            "\x1b[38;2;255;255;255m\x1b[49m▀\x1b[0m\n",
+        ];
+
+        assert_eq!(render(&frame), expected.concat());
+    }
+}
@@ -0,0 +1,134 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use aoc_core::Answer;
+
+use crate::days::workspace_directory;
+
+pub fn default_path() -> PathBuf {
+    workspace_directory().join("answers.json")
+}
+
+pub fn hash_input(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+// Answers recorded before there was a second year have no year, and are all for 2022
+fn first_year() -> u32 {
+    2022
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    #[serde(default = "first_year")]
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub input: String,
+    pub answer: Answer,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Verified,
+    Regression(Answer),
+    Unverified,
+}
+
+// Accepted answers for real inputs, keyed by a hash of the input so a changed input is never mistaken for a regression
+pub struct AnswerDb {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl AnswerDb {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let records = match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|err| format!("Could not read answers from {}: {err}", path.display()))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => vec![],
+            Err(err) => return Err(format!("Could not read answers from {}: {err}", path.display())),
+        };
+
+        Ok(AnswerDb {path: path.to_path_buf(), records})
+    }
+
+    pub fn save(&mut self) -> Result<(), String> {
+        self.records.sort_by(|a, b| (a.year, a.day, &a.input, a.part).cmp(&(b.year, b.day, &b.input, b.part)));
+
+        let json = serde_json::to_string_pretty(&self.records).unwrap();
+
+        fs::write(&self.path, json + "\n").map_err(|err| format!("Could not write answers to {}: {err}", self.path.display()))
+    }
+
+    fn find(&self, year: u32, day: u32, part: u8, input: &str) -> Option<&Record> {
+        self.records
+            .iter()
+            .find(|record| (record.year, record.day, record.part) == (year, day, part) && record.input == input)
+    }
+
+    pub fn check(&self, year: u32, day: u32, part: u8, input: &str, answer: &Answer) -> Check {
+        match self.find(year, day, part, input) {
+            Some(record) if record.answer == *answer => Check::Verified,
+            Some(record) => Check::Regression(record.answer.clone()),
+            None => Check::Unverified,
+        }
+    }
+
+    // Whether an answer has been accepted for the part on any input
+    pub fn has_answer(&self, year: u32, day: u32, part: u8) -> bool {
+        self.records.iter().any(|record| (record.year, record.day, record.part) == (year, day, part))
+    }
+
+    pub fn record(&mut self, year: u32, day: u32, part: u8, input: &str, answer: Answer) {
+        self.records.retain(|record| !((record.year, record.day, record.part) == (year, day, part) && record.input == input));
+        self.records.push(Record {year, day, part, input: input.to_string(), answer});
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn test_hash_input() {
+        assert_eq!(hash_input("abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[test]
+    fn test_check() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.json", process::id()));
+
+        let mut answers = AnswerDb::load(&path).unwrap();
+
+        answers.record(2022, 9, 1, "abcd", Answer::from(13));
+        answers.record(2022, 10, 2, "abcd", Answer::from("EHZFZHCZ"));
+        answers.save().unwrap();
+
+        let answers = AnswerDb::load(&path).unwrap();
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(answers.check(2022, 9, 1, "abcd", &Answer::from(13)), Check::Verified);
+        assert_eq!(answers.check(2022, 9, 1, "abcd", &Answer::from(14)), Check::Regression(Answer::from(13)));
+        assert_eq!(answers.check(2022, 9, 1, "ef01", &Answer::from(14)), Check::Unverified);
+        assert_eq!(answers.check(2023, 9, 1, "abcd", &Answer::from(14)), Check::Unverified);
+        assert_eq!(answers.check(2022, 10, 2, "abcd", &Answer::from("EHZFZHCZ")), Check::Verified);
+    }
+
+    #[test]
+    fn test_records_without_year() {
+        let records: Vec<Record> = serde_json::from_str(r#"[{"day": 9, "part": 1, "input": "abcd", "answer": {"type": "integer", "value": 13}}]"#).unwrap();
+
+        assert_eq!(records[0].year, 2022);
+    }
+}
@@ -0,0 +1,79 @@
+use serde::Serialize;
+
+use aoc_bench::{format_duration, measure, Stats, Table};
+use aoc_core::ParseError;
+
+use crate::days::Day;
+
+#[derive(Debug, Serialize)]
+pub struct Stage {
+    pub stage: String,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayBench {
+    pub year: u32,
+    pub day: u32,
+    pub title: String,
+    pub warmup: usize,
+    pub iterations: usize,
+    pub stages: Vec<Stage>,
+}
+
+// Parsing is timed on its own and each part reuses a single parsed input, the same split the runner reports
+pub fn bench(day: &Day, input: &str, warmup: usize, iterations: usize) -> Result<DayBench, ParseError> {
+    let parsed = day.solution.parse_dyn(input)?;
+
+    let mut stages = vec![Stage {
+        stage: "parse".to_string(),
+        stats: measure(warmup, iterations, || day.solution.parse_dyn(input)),
+    }];
+
+    [1, 2].into_iter().for_each(|part| {
+        stages.push(Stage {
+            stage: format!("part {part}"),
+            stats: measure(warmup, iterations, || day.solution.part_dyn(parsed.as_ref(), part)),
+        });
+    });
+
+    Ok(DayBench {year: day.year, day: day.day, title: day.title(), warmup, iterations, stages})
+}
+
+pub fn table(results: &[DayBench]) -> Table {
+    let mut table = Table::new(["Year", "Day", "Title", "Stage", "Mean", "Median", "Min", "Std dev"]);
+
+    results.iter().for_each(|result| {
+        result.stages.iter().for_each(|Stage {stage, stats}| {
+            table.push([
+                result.year.to_string(),
+                result.day.to_string(),
+                result.title.clone(),
+                stage.clone(),
+                format_duration(stats.mean),
+                format_duration(stats.median),
+                format_duration(stats.min),
+                format_duration(stats.std_dev),
+            ]);
+        });
+    });
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let stats = Stats::from_samples(vec![Duration::from_nanos(1500)]);
+
+        let json = serde_json::to_string(&Stage {stage: "parse".to_string(), stats}).unwrap();
+
+        assert_eq!(json, r#"{"stage":"parse","mean_ns":1500,"median_ns":1500,"min_ns":1500,"std_dev_ns":0}"#);
+    }
+}
@@ -0,0 +1,341 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ureq::Agent;
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const URL_ENV: &str = "AOC_URL";
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/FWFWLL/CompetitiveProgramming aoc runner";
+
+// Shortest gap between two requests to the server, shared by every run through a timestamp file
+const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    Wait(Duration),
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer"),
+            Verdict::Incorrect => write!(f, "That's not the right answer"),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            Verdict::TooLow => write!(f, "That's not the right answer, it is too low"),
+            Verdict::Wait(duration) => write!(f, "An answer was submitted too recently, wait {}s before trying again", duration.as_secs()),
+            Verdict::AlreadySolved => write!(f, "This part has already been solved"),
+        }
+    }
+}
+
+// "You have 1m 4s left to wait" -> 64s
+fn wait_time(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+
+    time.split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.len() - 1);
+            let value: u64 = value.parse().ok()?;
+
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+pub fn parse_verdict(response: &str) -> Option<Verdict> {
+    if response.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if response.contains("You gave an answer too recently") {
+        Some(Verdict::Wait(wait_time(response).unwrap_or(REQUEST_INTERVAL)))
+    } else if response.contains("That's not the right answer") {
+        if response.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else {
+            Some(Verdict::Incorrect)
+        }
+    } else if response.contains("You don't seem to be solving the right level") {
+        Some(Verdict::AlreadySolved)
+    } else {
+        None
+    }
+}
+
+pub struct Throttle {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(stamp: PathBuf, interval: Duration) -> Self {
+        Throttle {stamp, interval}
+    }
+
+    // Sleeps until `interval` has passed since the last request recorded in the stamp file, then records this one
+    fn wait(&self) -> Result<(), String> {
+        let now = || SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|nanos| nanos.trim().parse().ok())
+            .map(Duration::from_nanos);
+
+        if let Some(remaining) = last.and_then(|last| (last + self.interval).checked_sub(now())) {
+            thread::sleep(remaining.min(self.interval));
+        }
+
+        fs::write(&self.stamp, now().as_nanos().to_string()).map_err(|err| format!("Could not write {}: {err}", self.stamp.display()))
+    }
+}
+
+pub struct Client {
+    agent: Agent,
+    url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub fn new(url: &str, session: &str, throttle: Throttle) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Client {agent, url: url.trim_end_matches('/').to_string(), session: session.to_string(), throttle}
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        let session = env::var(SESSION_ENV).map_err(|_| format!("Set {SESSION_ENV} to the value of your session cookie"))?;
+        let url = env::var(URL_ENV).unwrap_or_else(|_| DEFAULT_URL.to_string());
+
+        let throttle = Throttle::new(env::temp_dir().join("aoc-last-request"), REQUEST_INTERVAL);
+
+        Ok(Client::new(&url, session.trim(), throttle))
+    }
+
+    fn check_status(status: u16, url: &str, body: &str) -> Result<(), String> {
+        match status {
+            200 => Ok(()),
+            400 | 401 => Err(format!("{url} rejected the session token ({status}), check {SESSION_ENV}")),
+            404 => Err(format!("{url} was not found, the puzzle may not be unlocked yet")),
+            500..=599 => Err(format!("{url} had a server error ({status}), try again later")),
+            _ => Err(format!("{url} returned {status}: {}", body.trim())),
+        }
+    }
+
+    // Inputs never change, so one already at `cache` is returned without asking the server again
+    pub fn input(&self, year: u32, day: u32, cache: &Path) -> Result<String, String> {
+        if let Ok(input) = fs::read_to_string(cache) {
+            return Ok(input);
+        }
+
+        let url = format!("{}/{year}/day/{day}/input", self.url);
+
+        self.throttle.wait()?;
+
+        let mut response = self.agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|err| format!("Could not fetch {url}: {err}"))?;
+
+        let body = response.body_mut().read_to_string().map_err(|err| format!("Could not read {url}: {err}"))?;
+
+        Client::check_status(response.status().as_u16(), &url, &body)?;
+
+        if let Some(directory) = cache.parent() {
+            fs::create_dir_all(directory).map_err(|err| format!("Could not create {}: {err}", directory.display()))?;
+        }
+
+        fs::write(cache, &body).map_err(|err| format!("Could not write {}: {err}", cache.display()))?;
+
+        Ok(body)
+    }
+
+    pub fn submit(&self, year: u32, day: u32, part: u8, answer: &str) -> Result<Verdict, String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.url);
+
+        self.throttle.wait()?;
+
+        let mut response = self.agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+            .map_err(|err| format!("Could not submit to {url}: {err}"))?;
+
+        let body = response.body_mut().read_to_string().map_err(|err| format!("Could not read {url}: {err}"))?;
+
+        Client::check_status(response.status().as_u16(), &url, &body)?;
+
+        parse_verdict(&body).ok_or_else(|| format!("Could not understand the response from {url}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::process;
+    use std::sync::mpsc::{self, Receiver};
+    use std::time::Instant;
+
+    // Stands in for the puzzle server, answering one connection per canned response and passing each request back
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut length = 0;
+
+                loop {
+                    let mut line = String::new();
+
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+
+                    request += &line;
+
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; length];
+
+                reader.read_exact(&mut content).unwrap();
+                request += &String::from_utf8(content).unwrap();
+
+                write!(reader.get_mut(), "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+
+                sender.send(request).unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-client-{}-{name}", process::id()))
+    }
+
+    fn client(url: &str, name: &str) -> Client {
+        Client::new(url, "secret", Throttle::new(temp_path(name).join("stamp"), Duration::ZERO))
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let article = |text: &str| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+
+        assert_eq!(parse_verdict(&article("That's the right answer!  You are one gold star closer to collecting enough star fruit.")), Some(Verdict::Correct));
+        assert_eq!(parse_verdict(&article("That's not the right answer.  If you're stuck, make sure you're using the full input data.")), Some(Verdict::Incorrect));
+        assert_eq!(parse_verdict(&article("That's not the right answer; your answer is too high.  Please wait one minute before trying again.")), Some(Verdict::TooHigh));
+        assert_eq!(parse_verdict(&article("That's not the right answer; your answer is too low.")), Some(Verdict::TooLow));
+        assert_eq!(parse_verdict(&article("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 4s left to wait.")), Some(Verdict::Wait(Duration::from_secs(64))));
+        assert_eq!(parse_verdict(&article("You gave an answer too recently.  You have 37s left to wait.")), Some(Verdict::Wait(Duration::from_secs(37))));
+        assert_eq!(parse_verdict(&article("You don't seem to be solving the right level.  Did you already complete it?")), Some(Verdict::AlreadySolved));
+        assert_eq!(parse_verdict("<html>Log in</html>"), None);
+    }
+
+    #[test]
+    fn test_input_is_cached() {
+        let (url, requests) = serve(vec![(200, "R 4\nU 4\n")]);
+
+        fs::create_dir_all(temp_path("cache")).unwrap();
+
+        let cache = temp_path("cache").join("2022/day-09.txt");
+        let client = client(&url, "cache");
+
+        assert_eq!(client.input(2022, 9, &cache), Ok("R 4\nU 4\n".to_string()));
+        assert_eq!(client.input(2022, 9, &cache), Ok("R 4\nU 4\n".to_string()));
+
+        fs::remove_dir_all(temp_path("cache")).unwrap();
+
+        let request = requests.recv().unwrap();
+
+        assert!(request.starts_with("GET /2022/day/9/input HTTP/1.1\r\n"));
+        assert!(request.contains("session=secret"));
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (url, _requests) = serve(vec![(404, "Not found"), (400, "Bad request"), (503, "Unavailable")]);
+
+        fs::create_dir_all(temp_path("errors")).unwrap();
+
+        let cache = temp_path("errors").join("day-25.txt");
+        let client = client(&url, "errors");
+
+        assert!(client.input(2022, 25, &cache).unwrap_err().ends_with("was not found, the puzzle may not be unlocked yet"));
+        assert!(client.input(2022, 25, &cache).unwrap_err().ends_with("rejected the session token (400), check AOC_SESSION"));
+        assert!(client.input(2022, 25, &cache).unwrap_err().ends_with("had a server error (503), try again later"));
+        assert!(!cache.exists());
+
+        fs::remove_dir_all(temp_path("errors")).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, requests) = serve(vec![(200, "<article><p>That's not the right answer; your answer is too low.</p></article>")]);
+
+        fs::create_dir_all(temp_path("submit")).unwrap();
+
+        assert_eq!(client(&url, "submit").submit(2022, 9, 2, "36"), Ok(Verdict::TooLow));
+
+        fs::remove_dir_all(temp_path("submit")).unwrap();
+
+        let request = requests.recv().unwrap();
+
+        assert!(request.starts_with("POST /2022/day/9/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=36"));
+    }
+
+    #[test]
+    fn test_throttle() {
+        let throttle = Throttle::new(temp_path("throttle"), Duration::from_millis(200));
+
+        let start = Instant::now();
+
+        throttle.wait().unwrap();
+        throttle.wait().unwrap();
+
+        fs::remove_file(temp_path("throttle")).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,98 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::{DynSolution, ParseError};
+use aoc_visual::{Recorder, Visualize};
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub directory: &'static str,
+    pub solution: &'static dyn DynSolution,
+}
+
+impl Day {
+    pub fn title(&self) -> String {
+        self.directory
+            .splitn(3, '-')
+            .nth(2)
+            .unwrap_or(self.directory)
+            .replace('-', " ")
+    }
+
+    pub fn path(&self) -> PathBuf {
+        year_directory(self.year).join(self.directory)
+    }
+}
+
+macro_rules! days {
+    ($($year:literal, $day:literal => $solution:path, $directory:literal;)*) => {
+        pub const DAYS: &[Day] = &[
+            $(
+                Day {
+                    year: $year,
+                    day: $day,
+                    directory: $directory,
+                    solution: &$solution,
+                },
+            )*
+        ];
+    };
+}
+
+days! {
+    2022, 1 => day_01::Day01, "Day-01-Calorie-Counting";
+    2022, 2 => day_02::Day02, "Day-02-Rock-Paper-Scissors";
+    2022, 3 => day_03::Day03, "Day-03-Rucksack-Reorganization";
+    2022, 4 => day_04::Day04, "Day-04-Camp-Cleanup";
+    2022, 5 => day_05::Day05, "Day-05-Supply-Stacks";
+    2022, 6 => day_06::Day06, "Day-06-Tuning-Trouble";
+    2022, 7 => day_07::Day07, "Day-07-No-Space-Left-On-Device";
+    2022, 8 => day_08::Day08, "Day-08-Treetop-Tree-House";
+    2022, 9 => day_09::Day09, "Day-09-Rope-Bridge";
+    2022, 10 => day_10::Day10, "Day-10-Cathode-Ray-Tube";
+    2022, 11 => day_11::Day11, "Day-11-Monkey-In-The-Middle";
+    2022, 12 => day_12::Day12, "Day-12-Hill-Climbing-Algorithm";
+    2022, 13 => day_13::Day13, "Day-13-Distress-Signal";
+    2022, 14 => day_14::Day14, "Day-14-Regolith-Reservoir";
+}
+
+pub type Visualizer = fn(&str, u8, &mut dyn Recorder) -> Result<(), ParseError>;
+
+fn visualize<V: Visualize>(input: &str, part: u8, recorder: &mut dyn Recorder) -> Result<(), ParseError> {
+    let parsed = V::parse_input(input)?;
+
+    V::visualize(&parsed, part, recorder);
+
+    Ok(())
+}
+
+// The days with a simulation worth watching
+pub fn visualizer(day: &Day) -> Option<Visualizer> {
+    match (day.year, day.day) {
+        (2022, 8) => Some(visualize::<day_08::Day08>),
+        (2022, 9) => Some(visualize::<day_09::Day09>),
+        (2022, 12) => Some(visualize::<day_12::Day12>),
+        (2022, 14) => Some(visualize::<day_14::Day14>),
+        _ => None,
+    }
+}
+
+pub fn workspace_directory() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .unwrap()
+}
+
+pub fn year_directory(year: u32) -> PathBuf {
+    workspace_directory().join(format!("Advent-of-Code-{year}"))
+}
+
+// What commands fall back to when no year is given
+pub fn latest_year() -> u32 {
+    DAYS.iter().map(|day| day.year).max().unwrap()
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
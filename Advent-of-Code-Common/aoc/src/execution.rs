@@ -0,0 +1,201 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_core::{Answer, ParseError};
+
+use crate::days::Day;
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+#[derive(Debug)]
+pub struct Execution {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl Execution {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|part| part.duration).sum::<Duration>()
+    }
+}
+
+// Parses the input once and runs each part on it, noting which part is running in `current` so a panic can be pinned
+// on it
+fn execute_tracked(day: &Day, input: &str, parts: &[u8], current: &Cell<Option<u8>>) -> Result<Execution, ParseError> {
+    let start = Instant::now();
+
+    let parsed = day.solution.parse_dyn(input)?;
+
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            current.set(Some(part));
+
+            let start = Instant::now();
+
+            let answer = day.solution.part_dyn(parsed.as_ref(), part);
+
+            PartRun {part, answer, duration: start.elapsed()}
+        })
+        .collect();
+
+    Ok(Execution {parse, parts})
+}
+
+pub fn execute(day: &Day, input: &str, parts: &[u8]) -> Result<Execution, ParseError> {
+    execute_tracked(day, input, parts, &Cell::new(None))
+}
+
+#[derive(Debug)]
+pub enum Failure {
+    Parse(ParseError),
+    // The part that was running, or None if parsing panicked
+    Panic {part: Option<u8>, message: String},
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Parse(err) => write!(f, "could not parse the input at {err}"),
+            Failure::Panic {part: Some(part), message} => write!(f, "part {part} panicked: {message}"),
+            Failure::Panic {part: None, message} => write!(f, "parsing panicked: {message}"),
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "panicked".to_string(),
+    }
+}
+
+// Runs a day like `execute`, turning a panic anywhere in it into a failure instead of unwinding further
+pub fn execute_isolated(day: &Day, input: &str, parts: &[u8]) -> Result<Execution, Failure> {
+    let current = Cell::new(None);
+
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| execute_tracked(day, input, parts, &current).map_err(Failure::Parse)));
+
+    outcome.unwrap_or_else(|payload| Err(Failure::Panic {part: current.get(), message: panic_message(payload)}))
+}
+
+// Runs every day on `jobs` threads and gives back an execution per day, in the order the days came in. A day's input is
+// parsed once by whichever thread picks it up, which then runs all of its parts. A panic fails its own day and nothing else
+pub fn execute_all(work: &[(&Day, &str)], parts: &[u8], jobs: usize) -> Vec<Result<Execution, Failure>> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        (0..jobs.clamp(1, work.len().max(1))).for_each(|_| {
+            let (next, sender) = (&next, sender.clone());
+
+            scope.spawn(move || {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+
+                    let Some(&(day, input)) = work.get(i) else {break};
+
+                    sender.send((i, execute_isolated(day, input, parts))).unwrap();
+                }
+            });
+        });
+    });
+
+    drop(sender);
+
+    let mut results: Vec<_> = receiver.into_iter().collect();
+    results.sort_by_key(|&(i, _)| i);
+
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::AtomicUsize;
+
+    use aoc_core::Solution;
+
+    use crate::days;
+
+    struct Panics;
+
+    impl Solution for Panics {
+        type Parsed = ();
+
+        fn parse(_: &str) -> Result<Self::Parsed, ParseError> {
+            Ok(())
+        }
+
+        fn part_1(_: &Self::Parsed) -> Answer {
+            Answer::from(1)
+        }
+
+        fn part_2(_: &Self::Parsed) -> Answer {
+            panic!("How are they equal!?")
+        }
+    }
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counts;
+
+    impl Solution for Counts {
+        type Parsed = ();
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            PARSES.fetch_add(1, Ordering::Relaxed);
+
+            if input.is_empty() {panic!("Nothing to parse")} else {Ok(())}
+        }
+
+        fn part_1(_: &Self::Parsed) -> Answer {
+            Answer::from(1)
+        }
+
+        fn part_2(_: &Self::Parsed) -> Answer {
+            Answer::from(2)
+        }
+    }
+
+    #[test]
+    fn test_parses_once_per_day() {
+        let counts = Day {year: 2022, day: 98, directory: "Day-98-Counts", solution: &Counts};
+
+        let results = execute_all(&[(&counts, "a"), (&counts, "b"), (&counts, "")], &[1, 2], 2);
+
+        assert_eq!(PARSES.load(Ordering::Relaxed), 3);
+        assert_eq!(results[0].as_ref().unwrap().parts.len(), 2);
+        assert_eq!(results[2].as_ref().unwrap_err().to_string(), "parsing panicked: Nothing to parse");
+    }
+
+    #[test]
+    fn test_execute_all() {
+        let panics = Day {year: 2022, day: 99, directory: "Day-99-Panics", solution: &Panics};
+
+        let work = [(days::find(2022, 1).unwrap(), "1000\n2000\n\n3000\n"), (&panics, ""), (days::find(2022, 6).unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n")];
+
+        let results = execute_all(&work, &[1, 2], 4);
+
+        let answers = |result: &Result<Execution, Failure>| result.as_ref().unwrap().parts.iter().map(|run| run.answer.clone()).collect::<Vec<Answer>>();
+
+        assert_eq!(answers(&results[0]), [Answer::from(3000), Answer::from(6000)]);
+        assert_eq!(answers(&results[2]), [Answer::from(7), Answer::from(19)]);
+        assert_eq!(results[1].as_ref().unwrap_err().to_string(), "part 2 panicked: How are they equal!?");
+    }
+}
@@ -0,0 +1,97 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+use aoc_core::input::normalize;
+
+use crate::days::Day;
+
+// Directory holding every input as `<dir>/<year>/day-<NN>.txt`, for keeping inputs out of the day crates
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    // An explicit `--input` wins, then the inputs directory from the environment, then the day's own input.txt
+    pub fn resolve(day: &Day, input: Option<&str>) -> Self {
+        Source::resolve_with(day, input, env::var_os(INPUTS_ENV).map(PathBuf::from).as_deref())
+    }
+
+    fn resolve_with(day: &Day, input: Option<&str>, inputs: Option<&Path>) -> Self {
+        match (input, inputs) {
+            (Some("-"), _) => Source::Stdin,
+            (Some(path), _) => Source::File(PathBuf::from(path)),
+            (None, Some(inputs)) => Source::File(inputs.join(day.year.to_string()).join(format!("day-{:02}.txt", day.day))),
+            (None, None) => Source::File(day.path().join("input.txt")),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            Source::Stdin => {
+                let mut buffer = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut buffer)
+                    .map_err(|err| format!("Could not read input from stdin: {err}"))?;
+
+                Ok(buffer)
+            },
+            Source::File(path) => fs::read_to_string(path).map_err(|err| match err.kind() {
+                ErrorKind::NotFound => format!("No input found at {}", path.display()),
+                _ => format!("Could not read input from {}: {err}", path.display()),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+// Normalized straight away, so a copy saved with different line endings is recognised by its hash as the same input
+pub fn read_input(day: &Day, input: Option<&str>) -> Result<String, String> {
+    let source = Source::resolve(day, input);
+
+    source.read().map(|input| normalize(&input)).map_err(|err| match &source {
+        Source::File(path) if input.is_none() && !path.exists() => {
+            format!("{err}; save it there, run `aoc fetch --year {} --day {}`, pass --input <path> or set {INPUTS_ENV} to a directory of <year>/day-<NN>.txt files", day.year, day.day)
+        },
+        _ => err,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::days;
+
+    #[test]
+    fn test_resolve() {
+        let day = days::find(2022, 9).unwrap();
+
+        assert_eq!(Source::resolve_with(day, Some("-"), None), Source::Stdin);
+        assert_eq!(Source::resolve_with(day, Some("my.txt"), Some(Path::new("/inputs"))), Source::File(PathBuf::from("my.txt")));
+        assert_eq!(Source::resolve_with(day, None, Some(Path::new("/inputs"))), Source::File(PathBuf::from("/inputs/2022/day-09.txt")));
+        assert_eq!(Source::resolve_with(day, None, None), Source::File(day.path().join("input.txt")));
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let error = Source::File(PathBuf::from("/nowhere/day-09.txt")).read().unwrap_err();
+
+        assert_eq!(error, "No input found at /nowhere/day-09.txt");
+    }
+}
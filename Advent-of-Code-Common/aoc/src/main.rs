@@ -0,0 +1,751 @@
+mod answers;
+mod bench;
+mod client;
+mod days;
+mod execution;
+mod input;
+mod profile;
+mod readme;
+mod report;
+mod scaffold;
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use aoc_bench::{format_duration, Table};
+use aoc_core::Answer;
+use aoc_visual::{Animation, Recorder, Sample, Still, Terminal};
+
+use answers::{hash_input, AnswerDb, Check};
+use client::{Client, Verdict};
+use days::{Day, DAYS};
+use execution::{execute, execute_all, execute_isolated, Execution, Failure};
+use input::{read_input, Source};
+use report::Status;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solution runner")]
+struct Cli {
+    /// Year of the puzzles (the latest year for a single day, every year when running them all)
+    #[arg(long, global = true)]
+    year: Option<u32>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day with --all
+    Run(RunArgs),
+
+    /// Time parsing and both parts of a day, or of every day with an input
+    Bench(BenchArgs),
+
+    /// Count the allocations, bytes allocated and peak memory of parsing and both parts (needs --features profile)
+    Profile(ProfileArgs),
+
+    /// Check every day that has an input against its recorded answers
+    Verify,
+
+    /// Create a new day crate from Day-00-Template and register it with the runner, starting a new year if needed
+    New(NewArgs),
+
+    /// Regenerate the progress tables in README.md from the days and problems on disk
+    Readme(ReadmeArgs),
+
+    /// Download a day's input to where `run` reads it from, using the session token in $AOC_SESSION
+    Fetch(FetchArgs),
+
+    /// Submit an answer for a day, solving the day's input when no answer is given
+    Submit(SubmitArgs),
+
+    /// Play a day's simulation in the terminal, or save it as a .ppm or .png still or an animated .gif
+    Visualize(VisualizeArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+
+    /// Only run this part (both parts are run by default)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2), requires = "day")]
+    part: Option<u8>,
+
+    /// Input file, or `-` to read from stdin (defaults to $AOC_INPUTS/<year>/day-NN.txt or the day's input.txt)
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    /// Run every day in sequence and print a summary table
+    #[arg(long)]
+    all: bool,
+
+    /// Record the answers as verified for this input, so later runs flag any change as a regression
+    #[arg(long)]
+    record: bool,
+
+    /// Print one JSON object per day and part, with timings, the input hash and whether it succeeded
+    #[arg(long, conflicts_with = "record")]
+    json: bool,
+
+    /// Threads to run days on, where a day that panics only fails itself
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: u64,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Markdown,
+    Json,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark (every day with an input by default)
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Input file, or `-` to read from stdin (defaults to $AOC_INPUTS/<year>/day-NN.txt or the day's input.txt)
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Timed runs per stage
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct ProfileArgs {
+    /// Day to profile (every day with an input by default)
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Input file, or `-` to read from stdin (defaults to $AOC_INPUTS/<year>/day-NN.txt or the day's input.txt)
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(long)]
+    day: u32,
+
+    /// Puzzle title, used for the directory name
+    #[arg(long)]
+    title: String,
+}
+
+#[derive(Args)]
+struct ReadmeArgs {
+    /// Timed runs per day or problem (0 leaves the timings out)
+    #[arg(long, default_value_t = 10)]
+    iterations: usize,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to download
+    #[arg(long)]
+    day: u32,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit
+    #[arg(long)]
+    day: u32,
+
+    /// Part the answer is for
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to submit (defaults to the answer the day gives for its input)
+    #[arg(long)]
+    answer: Option<String>,
+}
+
+#[derive(Args)]
+struct VisualizeArgs {
+    /// Day to visualize (8, 9, 12 or 14)
+    #[arg(long)]
+    day: u32,
+
+    /// Part whose simulation to show
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Input file, or `-` to read from stdin (defaults to $AOC_INPUTS/<year>/day-NN.txt or the day's input.txt)
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Image to write instead of playing in the terminal: .ppm and .png keep the final frame, .gif keeps them all
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Pixels per cell in images
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+
+    /// Frames per second in the terminal and in GIFs
+    #[arg(long, default_value_t = 30)]
+    fps: u32,
+
+    /// Only show every Nth frame, for long simulations (the final frame is always shown)
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    every: u64,
+}
+
+fn find_day(year: Option<u32>, day: u32) -> Result<&'static Day, String> {
+    let year = year.unwrap_or_else(days::latest_year);
+
+    days::find(year, day).ok_or_else(|| format!("Day {day} of {year} has not been solved yet"))
+}
+
+// One day, or every day of one year, or of every year when none is given
+fn select_days(year: Option<u32>, day: Option<u32>) -> Result<Vec<&'static Day>, String> {
+    if let Some(day) = day {
+        return Ok(vec![find_day(year, day)?]);
+    }
+
+    let days: Vec<&Day> = DAYS.iter().filter(|day| year.is_none_or(|year| day.year == year)).collect();
+
+    match year {
+        Some(year) if days.is_empty() => Err(format!("No days of {year} have been solved yet")),
+        _ => Ok(days),
+    }
+}
+
+fn display_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Picture(picture) => picture.render('█', ' '),
+        answer => answer.to_string(),
+    }
+}
+
+fn check_answers(answers: &AnswerDb, day: &Day, input: &str, execution: &Execution) -> Vec<(u8, Check)> {
+    let input = hash_input(input);
+
+    execution.parts
+        .iter()
+        .map(|run| (run.part, answers.check(day.year, day.day, run.part, &input, &run.answer)))
+        .collect()
+}
+
+fn record_answers(answers: &mut AnswerDb, day: &Day, input: &str, execution: &Execution) {
+    let input = hash_input(input);
+
+    execution.parts
+        .iter()
+        .for_each(|run| answers.record(day.year, day.day, run.part, &input, run.answer.clone()));
+}
+
+fn regression_message(day: &Day, part: u8, expected: &Answer, answer: &Answer) -> String {
+    format!("Regression in {} day {} part {part}: got {}, but {} was verified for this input", day.year, day.day, display_answer(answer), display_answer(expected))
+}
+
+fn run_day(args: &RunArgs, year: Option<u32>, day_number: u32) -> Result<(), String> {
+    let day = find_day(year, day_number)?;
+
+    let source = Source::resolve(day, args.input.as_deref());
+
+    let input = read_input(day, args.input.as_deref())?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let execution = execute(day, &input, &parts).map_err(|err| format!("Could not parse day {day_number} input from {source} at {err}"))?;
+
+    execution.parts.iter().for_each(|run| {
+        let answer = display_answer(&run.answer);
+
+        match (parts.len(), &run.answer) {
+            (1, _) => println!("{answer}"),
+            (_, Answer::Picture(_)) => println!("Part {}:\n{answer}", run.part),
+            _ => println!("Part {}: {answer}", run.part),
+        }
+    });
+
+    let mut answers = AnswerDb::load(&answers::default_path())?;
+
+    if args.record {
+        record_answers(&mut answers, day, &input, &execution);
+
+        return answers.save();
+    }
+
+    let regressions: Vec<String> = check_answers(&answers, day, &input, &execution)
+        .into_iter()
+        .zip(&execution.parts)
+        .filter_map(|((part, check), run)| match check {
+            Check::Regression(expected) => Some(regression_message(day, part, &expected, &run.answer)),
+            _ => None,
+        })
+        .collect();
+
+    if regressions.is_empty() {Ok(())} else {Err(regressions.join("\n"))}
+}
+
+fn check_summary(checks: &[(u8, Check)]) -> String {
+    let regressions: Vec<String> = checks
+        .iter()
+        .filter(|(_, check)| matches!(check, Check::Regression(_)))
+        .map(|(part, _)| part.to_string())
+        .collect();
+
+    let verified = checks
+        .iter()
+        .filter(|(_, check)| *check == Check::Verified)
+        .count();
+
+    match (regressions.is_empty(), verified) {
+        (false, _) => format!("REGRESSION (part {})", regressions.join(", ")),
+        (true, 0) => "-".to_string(),
+        (true, verified) if verified == checks.len() => "verified".to_string(),
+        (true, _) => "partly verified".to_string(),
+    }
+}
+
+// A day's input and how running it went, or why there was no input to run
+type DayOutcome = Result<(String, Result<Execution, Failure>), String>;
+
+// Reads every day's input up front, then runs all the days that have one on `jobs` threads
+fn execute_days(days: &[&Day], input: Option<&str>, parts: &[u8], jobs: u64) -> Vec<DayOutcome> {
+    let inputs: Vec<Result<String, String>> = days.iter().map(|day| read_input(day, input)).collect();
+
+    let work: Vec<(&Day, &str)> = days
+        .iter()
+        .zip(&inputs)
+        .filter_map(|(&day, input)| Some((day, input.as_deref().ok()?)))
+        .collect();
+
+    let mut results = execute_all(&work, parts, jobs as usize).into_iter();
+
+    inputs
+        .into_iter()
+        .map(|input| input.map(|input| (input, results.next().unwrap())))
+        .collect()
+}
+
+fn run_all(args: &RunArgs, year: Option<u32>) -> Result<(), String> {
+    let mut answers = AnswerDb::load(&answers::default_path())?;
+    let mut regressions = 0;
+    let mut parse_errors = 0;
+    let mut panics = 0;
+
+    let mut table = Table::new(["Year", "Day", "Title", "Part 1", "Part 2", "Parse", "Time", "Check"]);
+    let mut pictures = vec![];
+
+    let start = Instant::now();
+
+    let days = select_days(year, None)?;
+
+    days.iter().zip(execute_days(&days, None, &[1, 2], args.jobs)).for_each(|(day, outcome)| {
+        let (input, execution) = match outcome {
+            Ok((input, Ok(execution))) => (input, execution),
+            Ok((_, Err(Failure::Parse(err)))) => {
+                parse_errors += 1;
+                table.push([day.year.to_string(), day.day.to_string(), day.title(), "parse error".to_string(), err.summary(), "-".to_string(), "-".to_string()]);
+
+                return;
+            },
+            Ok((_, Err(failure))) => {
+                panics += 1;
+                table.push([day.year.to_string(), day.day.to_string(), day.title(), "panicked".to_string(), failure.to_string(), "-".to_string(), "-".to_string()]);
+
+                return;
+            },
+            Err(_) => {
+                table.push([day.year.to_string(), day.day.to_string(), day.title(), "no input".to_string(), "no input".to_string(), "-".to_string(), "-".to_string()]);
+
+                return;
+            },
+        };
+
+        let results: Vec<String> = execution.parts
+            .iter()
+            .map(|run| match run.answer {
+                Answer::Picture(_) => {
+                    pictures.push((day.year, day.day, run.part, display_answer(&run.answer)));
+
+                    "(see below)".to_string()
+                },
+                _ => run.answer.to_string(),
+            })
+            .collect();
+
+        let checks = check_answers(&answers, day, &input, &execution);
+
+        regressions += checks.iter().filter(|(_, check)| matches!(check, Check::Regression(_))).count();
+
+        if args.record {
+            record_answers(&mut answers, day, &input, &execution);
+        }
+
+        table.push([day.year.to_string(), day.day.to_string(), day.title(), results[0].clone(), results[1].clone(), format_duration(execution.parse), format_duration(execution.total()), check_summary(&checks)]);
+    });
+
+    print!("{table}");
+
+    pictures.iter().for_each(|(year, day, part, result)| {
+        println!();
+        println!("{year} day {day} part {part}:");
+        println!("{result}");
+    });
+
+    println!();
+    println!("Total: {}", format_duration(start.elapsed()));
+
+    if args.record {
+        answers.save()?;
+    }
+
+    let failures: Vec<String> = [
+        (regressions > 0 && !args.record).then(|| format!("{regressions} answer(s) changed since they were verified")),
+        (parse_errors > 0).then(|| format!("{parse_errors} day(s) could not parse their input")),
+        (panics > 0).then(|| format!("{panics} day(s) panicked")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if failures.is_empty() {Ok(())} else {Err(failures.join("\n"))}
+}
+
+fn run_json(args: &RunArgs, year: Option<u32>) -> Result<(), String> {
+    let days = select_days(year, args.day)?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let answers = AnswerDb::load(&answers::default_path())?;
+
+    // Running everything skips days without an input, but asking for one day means it has to be there
+    let mut failures = 0;
+
+    days.iter().zip(execute_days(&days, args.input.as_deref(), &parts, args.jobs)).for_each(|(day, outcome)| {
+        let reports = match outcome {
+            Ok((input, result)) => report::reports(day, &input, &parts, result, &answers),
+            Err(err) => report::missing_input(day, &parts, &err),
+        };
+
+        reports
+            .iter()
+            .for_each(|report| {
+                if report.status != Status::Ok && (report.status != Status::NoInput || args.day.is_some()) {
+                    failures += 1;
+                }
+
+                println!("{}", serde_json::to_string(report).unwrap());
+            });
+    });
+
+    if failures == 0 {Ok(())} else {Err(format!("{failures} part(s) failed"))}
+}
+
+fn verify(year: Option<u32>) -> Result<(), String> {
+    let answers = AnswerDb::load(&answers::default_path())?;
+
+    let mut table = Table::new(["Year", "Day", "Title", "Part 1", "Part 2"]);
+    let mut failures = vec![];
+
+    select_days(year, None)?.into_iter().for_each(|day| {
+        let Ok(input) = read_input(day, None) else {
+            table.push([day.year.to_string(), day.day.to_string(), day.title(), "no input".to_string(), "no input".to_string()]);
+
+            return;
+        };
+
+        let execution = match execute_isolated(day, &input, &[1, 2]) {
+            Ok(execution) => execution,
+            Err(Failure::Parse(err)) => {
+                failures.push(format!("Could not parse day {} input at {err}", day.day));
+                table.push([day.year.to_string(), day.day.to_string(), day.title(), "parse error".to_string(), err.summary()]);
+
+                return;
+            },
+            Err(failure) => {
+                failures.push(format!("Day {} {failure}", day.day));
+                table.push([day.year.to_string(), day.day.to_string(), day.title(), "panicked".to_string(), failure.to_string()]);
+
+                return;
+            },
+        };
+
+        let statuses: Vec<String> = check_answers(&answers, day, &input, &execution)
+            .into_iter()
+            .zip(&execution.parts)
+            .map(|((part, check), run)| match check {
+                Check::Verified => "ok".to_string(),
+                Check::Unverified => "unverified".to_string(),
+                Check::Regression(expected) => {
+                    failures.push(regression_message(day, part, &expected, &run.answer));
+
+                    "REGRESSION".to_string()
+                },
+            })
+            .collect();
+
+        table.push([day.year.to_string(), day.day.to_string(), day.title(), statuses[0].clone(), statuses[1].clone()]);
+    });
+
+    print!("{table}");
+
+    if failures.is_empty() {Ok(())} else {Err(failures.join("\n"))}
+}
+
+fn run_bench(args: &BenchArgs, year: Option<u32>) -> Result<(), String> {
+    let mut results = vec![];
+
+    for day in select_days(year, args.day)? {
+        let input = match read_input(day, args.input.as_deref()) {
+            Ok(input) => input,
+            Err(err) if args.day.is_some() => return Err(err),
+            Err(_) => {
+                eprintln!("Skipping day {}: no input", day.day);
+
+                continue;
+            },
+        };
+
+        eprintln!("Benchmarking day {}...", day.day);
+
+        let result = bench::bench(day, &input, args.warmup, args.iterations as usize).map_err(|err| format!("Could not parse day {} input at {err}", day.day))?;
+
+        results.push(result);
+    }
+
+    match args.format {
+        Format::Table => print!("{}", bench::table(&results)),
+        Format::Markdown => print!("{}", bench::table(&results).markdown()),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&results).unwrap()),
+    }
+
+    Ok(())
+}
+
+fn run_profile(args: &ProfileArgs, year: Option<u32>) -> Result<(), String> {
+    if !profile::ENABLED {
+        return Err("Allocations are only counted in a build with the profiling allocator, run it with `cargo run --release --features profile -p aoc -- profile`".to_string());
+    }
+
+    let mut results = vec![];
+
+    for day in select_days(year, args.day)? {
+        let input = match read_input(day, args.input.as_deref()) {
+            Ok(input) => input,
+            Err(err) if args.day.is_some() => return Err(err),
+            Err(_) => {
+                eprintln!("Skipping day {}: no input", day.day);
+
+                continue;
+            },
+        };
+
+        let result = profile::profile(day, &input).map_err(|err| format!("Could not parse day {} input at {err}", day.day))?;
+
+        results.push(result);
+    }
+
+    match args.format {
+        Format::Table => print!("{}", profile::table(&results)),
+        Format::Markdown => print!("{}", profile::table(&results).markdown()),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&results).unwrap()),
+    }
+
+    Ok(())
+}
+
+fn update_readme(args: &ReadmeArgs) -> Result<(), String> {
+    let answers = AnswerDb::load(&answers::default_path())?;
+
+    let path = days::workspace_directory().join("README.md");
+
+    let readme = fs::read_to_string(&path).map_err(|err| format!("Could not read {}: {err}", path.display()))?;
+
+    let progress = readme::render(&readme::advent_of_code(&answers, args.iterations), &readme::project_euler(args.iterations));
+
+    fs::write(&path, readme::splice(&readme, &progress)?).map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+
+    println!("Updated {}", path.display());
+
+    Ok(())
+}
+
+fn fetch(year: Option<u32>, day_number: u32) -> Result<(), String> {
+    let year = year.unwrap_or_else(days::latest_year);
+
+    let day = days::find(year, day_number).ok_or_else(|| format!("Day {day_number} of {year} has not been created yet, run `aoc new` first"))?;
+
+    let Source::File(path) = Source::resolve(day, None) else {unreachable!()};
+
+    if path.exists() {
+        println!("Input for day {day_number} is already at {}", path.display());
+
+        return Ok(());
+    }
+
+    Client::from_env()?.input(year, day_number, &path)?;
+
+    println!("Saved input for day {day_number} to {}", path.display());
+
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs, year: Option<u32>) -> Result<(), String> {
+    let day = find_day(year, args.day)?;
+
+    let input = read_input(day, None)?;
+
+    let answer = match &args.answer {
+        Some(answer) => answer.parse::<i128>().map(Answer::from).unwrap_or_else(|_| Answer::from(answer.as_str())),
+        None => {
+            let execution = execute(day, &input, &[args.part]).map_err(|err| format!("Could not parse day {} input at {err}", args.day))?;
+
+            execution.parts[0].answer.clone()
+        },
+    };
+
+    // Pictures are read off the terminal, so only their lettering can be submitted
+    let text = match &answer {
+        Answer::Picture(_) => return Err(format!("Day {} part {} is a picture, read it and pass --answer", args.day, args.part)),
+        answer => answer.to_string(),
+    };
+
+    let mut answers = AnswerDb::load(&answers::default_path())?;
+
+    if answers.check(day.year, day.day, args.part, &hash_input(&input), &answer) == Check::Verified {
+        println!("{text} is already verified for this input");
+
+        return Ok(());
+    }
+
+    let verdict = Client::from_env()?.submit(day.year, args.day, args.part, &text)?;
+
+    println!("{text}: {verdict}");
+
+    match verdict {
+        Verdict::Correct => {
+            answers.record(day.year, day.day, args.part, &hash_input(&input), answer);
+
+            answers.save()
+        },
+        Verdict::AlreadySolved => Ok(()),
+        _ => Err(format!("Day {} part {} was not accepted", args.day, args.part)),
+    }
+}
+
+fn visualize(args: &VisualizeArgs, year: Option<u32>) -> Result<(), String> {
+    let day = find_day(year, args.day)?;
+
+    let visualizer = days::visualizer(day).ok_or_else(|| format!("Day {} of {} has no visualization", args.day, day.year))?;
+
+    let source = Source::resolve(day, args.input.as_deref());
+
+    let input = read_input(day, args.input.as_deref())?;
+
+    let play = |recorder: &mut dyn Recorder| {
+        let mut sample = Sample::new(recorder, args.every as usize);
+
+        visualizer(&input, args.part, &mut sample).map_err(|err| format!("Could not parse day {} input from {source} at {err}", args.day))?;
+
+        sample.finish();
+
+        Ok::<(), String>(())
+    };
+
+    let Some(path) = &args.output else {
+        let mut terminal = Terminal::new(io::stdout().lock(), args.fps);
+
+        play(&mut terminal)?;
+
+        return terminal.finish().map_err(|err| format!("Could not draw to the terminal: {err}"));
+    };
+
+    let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or_default().to_lowercase();
+
+    if !["ppm", "png", "gif"].contains(&extension.as_str()) {
+        return Err(format!("Can only write .ppm, .png or .gif files, not {}", path.display()));
+    }
+
+    let write_error = |err: io::Error| format!("Could not write {}: {err}", path.display());
+
+    let mut out = BufWriter::new(File::create(path).map_err(write_error)?);
+
+    if extension == "gif" {
+        let mut animation = Animation::new(out, args.fps, args.scale);
+
+        play(&mut animation)?;
+
+        return animation.finish().and_then(|mut out| out.flush()).map_err(write_error);
+    }
+
+    let mut still = Still::new();
+
+    play(&mut still)?;
+
+    let frame = aoc_visual::scale(&still.last().ok_or("The simulation did not produce a frame")?, args.scale);
+
+    let written = if extension == "png" {aoc_visual::write_png(&mut out, &frame)} else {aoc_visual::write_ppm(&mut out, &frame)};
+
+    written.and_then(|_| out.flush()).map_err(write_error)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let year = cli.year;
+
+    let outcome = match cli.command {
+        Command::Run(args) if args.json => run_json(&args, year),
+        Command::Run(args) => match args.day {
+            Some(day) => run_day(&args, year, day),
+            None => run_all(&args, year),
+        },
+        Command::Bench(args) => run_bench(&args, year),
+        Command::Profile(args) => run_profile(&args, year),
+        Command::Verify => verify(year),
+        Command::New(args) => {
+            let year = year.unwrap_or_else(days::latest_year);
+
+            scaffold::new_day(year, args.day, &args.title).map(|directory| {
+                println!("Created Advent-of-Code-{year}/{directory}");
+            })
+        },
+        Command::Readme(args) => update_readme(&args),
+        Command::Fetch(args) => fetch(year, args.day),
+        Command::Submit(args) => submit(&args, year),
+        Command::Visualize(args) => visualize(&args, year),
+    };
+
+    match outcome {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+
+            ExitCode::FAILURE
+        },
+    }
+}
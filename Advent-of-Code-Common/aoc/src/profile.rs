@@ -0,0 +1,207 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use aoc_bench::{format_duration, Table};
+use aoc_core::ParseError;
+
+use crate::days::Day;
+
+// Counting every allocation slows everything else down, so the allocator is only installed with `--features profile`
+pub const ENABLED: bool = cfg!(feature = "profile");
+
+#[cfg(feature = "profile")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// The system allocator, keeping count of allocations, of the bytes they asked for and of how much is live at once
+#[cfg_attr(not(feature = "profile"), allow(dead_code))]
+pub struct Counting;
+
+#[cfg_attr(not(feature = "profile"), allow(dead_code))]
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    // A resize counts as a new allocation of the new size, which is what it costs whenever the block has to move
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+
+        if !new.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            allocated(new_size);
+        }
+
+        new
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Usage {
+    pub allocations: usize,
+    #[serde(rename = "allocated_bytes")]
+    pub allocated: usize,
+    #[serde(rename = "peak_bytes")]
+    pub peak: usize,
+}
+
+// What `run` allocates, with the peak measured from however much was already live when it started
+fn measure<T>(run: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let baseline = CURRENT.load(Ordering::Relaxed);
+
+    PEAK.store(baseline, Ordering::Relaxed);
+
+    let result = run();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+    };
+
+    (result, usage)
+}
+
+fn as_nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+#[derive(Debug, Serialize)]
+pub struct StageProfile {
+    pub stage: String,
+    #[serde(rename = "time_ns", serialize_with = "as_nanos")]
+    pub time: Duration,
+    #[serde(flatten)]
+    pub usage: Usage,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayProfile {
+    pub year: u32,
+    pub day: u32,
+    pub title: String,
+    pub stages: Vec<StageProfile>,
+}
+
+fn stage<T>(stage: String, run: impl FnOnce() -> T) -> (T, StageProfile) {
+    let start = Instant::now();
+
+    let (result, usage) = measure(run);
+
+    (result, StageProfile {stage, time: start.elapsed(), usage})
+}
+
+// A single run of each stage, split the same way as the runner and `bench`: parsing, then each part on the parsed input
+pub fn profile(day: &Day, input: &str) -> Result<DayProfile, ParseError> {
+    let (parsed, parse) = stage("parse".to_string(), || day.solution.parse_dyn(input));
+
+    let parsed = parsed?;
+
+    let mut stages = vec![parse];
+
+    [1, 2].into_iter().for_each(|part| {
+        stages.push(stage(format!("part {part}"), || day.solution.part_dyn(parsed.as_ref(), part)).1);
+    });
+
+    Ok(DayProfile {year: day.year, day: day.day, title: day.title(), stages})
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{bytes} B"),
+        1024..=1_048_575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+pub fn table(results: &[DayProfile]) -> Table {
+    let mut table = Table::new(["Year", "Day", "Title", "Stage", "Time", "Allocations", "Allocated", "Peak"]);
+
+    results.iter().for_each(|result| {
+        result.stages.iter().for_each(|StageProfile {stage, time, usage}| {
+            table.push([
+                result.year.to_string(),
+                result.day.to_string(),
+                result.title.clone(),
+                stage.clone(),
+                format_duration(*time),
+                usage.allocations.to_string(),
+                format_bytes(usage.allocated),
+                format_bytes(usage.peak),
+            ]);
+        });
+    });
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1_048_576), "3.0 MiB");
+    }
+
+    // Drives the allocator by hand, so it is counted whether or not it is the global one. Other threads only share the
+    // counters when it is, so the counts are lower bounds
+    #[test]
+    fn test_counting() {
+        let layout = Layout::from_size_align(4096, 8).unwrap();
+
+        let ((), usage) = measure(|| unsafe {
+            let a = Counting.alloc(layout);
+            let b = Counting.realloc(a, layout, 8192);
+
+            Counting.dealloc(b, Layout::from_size_align(8192, 8).unwrap());
+        });
+
+        assert!(usage.allocations >= 2);
+        assert!(usage.allocated >= 4096 + 8192);
+        assert!(usage.peak >= 8192);
+    }
+}
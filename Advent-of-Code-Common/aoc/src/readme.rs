@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use aoc_bench::{format_duration, Table};
+
+use crate::answers::AnswerDb;
+use crate::bench;
+use crate::days::{self, workspace_directory, year_directory};
+use crate::input::read_input;
+
+// Everything between the markers is generated, so the rest of the README can be edited by hand
+const START: &str = "<!-- progress:start -->";
+const END: &str = "<!-- progress:end -->";
+
+// Two parts a day for 25 days
+const STARS: usize = 50;
+
+#[derive(Debug, PartialEq)]
+pub struct Entry {
+    pub number: u32,
+    pub title: String,
+    pub stars: usize,
+    pub examples: usize,
+    pub time: Option<Duration>,
+    pub link: String,
+}
+
+// "Day-09-Rope-Bridge" -> (9, "Rope Bridge")
+fn split_directory(directory: &str) -> Option<(u32, String)> {
+    let mut words = directory.splitn(3, '-');
+
+    let (_, number, title) = (words.next()?, words.next()?.parse().ok()?, words.next()?);
+
+    Some((number, title.replace('-', " ")))
+}
+
+// Numbered solution directories under `root`, in order, skipping the template
+fn directories(root: &Path, prefix: &str) -> Vec<(u32, String, String)> {
+    let mut directories: Vec<(u32, String, String)> = fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| name.starts_with(prefix))
+                .filter_map(|name| {
+                    let (number, title) = split_directory(&name)?;
+
+                    Some((number, title, name))
+                })
+                .filter(|&(number, _, _)| number > 0)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    directories.sort();
+
+    directories
+}
+
+// Every Advent-of-Code-<year> directory, oldest first
+fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = fs::read_dir(workspace_directory())
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter_map(|name| name.strip_prefix("Advent-of-Code-")?.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    years.sort();
+
+    years
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Year {
+    pub year: u32,
+    pub days: Vec<Entry>,
+}
+
+// A day earns a star only for a part whose answer was accepted, and separately counts the parts with an expected answer
+// for an example, which says the solution works but not that it was ever submitted. Days registered with the runner
+// and with an input are benchmarked, the time being the median of parsing plus both parts
+pub fn advent_of_code(answers: &AnswerDb, iterations: usize) -> Vec<Year> {
+    years()
+        .into_iter()
+        .map(|year| Year {year, days: advent_of_code_year(answers, year, iterations)})
+        .collect()
+}
+
+fn advent_of_code_year(answers: &AnswerDb, year: u32, iterations: usize) -> Vec<Entry> {
+    directories(&year_directory(year), "Day-")
+        .into_iter()
+        .map(|(number, title, directory)| {
+            let path = year_directory(year).join(&directory);
+
+            let examples = fs::read_to_string(path.join("examples.toml")).unwrap_or_default();
+
+            let stars = [1, 2]
+                .into_iter()
+                .filter(|&part| answers.has_answer(year, number, part))
+                .count();
+
+            let examples = aoc_examples::solved_parts(&examples).map_or(0, |parts| [1, 2].into_iter().filter(|part| parts.contains(part)).count());
+
+            let time = days::find(year, number)
+                .filter(|_| iterations > 0)
+                .and_then(|day| Some((day, read_input(day, None).ok()?)))
+                .and_then(|(day, input)| bench::bench(day, &input, 1, iterations).ok())
+                .map(|result| result.stages.iter().map(|stage| stage.stats.median).sum());
+
+            Entry {number, title, stars, examples, time, link: format!("Advent-of-Code-{year}/{directory}/src/lib.rs")}
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct ProblemBench {
+    problem: u32,
+    median_ns: u64,
+}
+
+// Problems live in their own workspace, so they are timed by its `euler bench` rather than in this process
+fn time_problems(root: &Path, iterations: usize) -> Option<HashMap<u32, Duration>> {
+    let output = Command::new("cargo")
+        .args(["run", "--release", "--quiet", "-p", "euler", "--manifest-path"])
+        .arg(root.join("Cargo.toml"))
+        .args(["--", "bench", "--format", "json", "--warmup", "1", "--iterations"])
+        .arg(iterations.to_string())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    let results: Vec<ProblemBench> = serde_json::from_slice(&output.stdout).ok()?;
+
+    Some(results.into_iter().map(|result| (result.problem, Duration::from_nanos(result.median_ns))).collect())
+}
+
+pub fn project_euler(iterations: usize) -> Vec<Entry> {
+    let root = workspace_directory().join("Project-Euler");
+
+    let times = if iterations > 0 {time_problems(&root, iterations).unwrap_or_default()} else {HashMap::new()};
+
+    directories(&root, "Problem-")
+        .into_iter()
+        .map(|(number, title, directory)| {
+            Entry {number, title, stars: 1, examples: 0, time: times.get(&number).copied(), link: format!("Project-Euler/{directory}/src/lib.rs")}
+        })
+        .collect()
+}
+
+pub fn render(advent_of_code: &[Year], project_euler: &[Entry]) -> String {
+    let time = |entry: &Entry| entry.time.map_or("-".to_string(), format_duration);
+    let link = |entry: &Entry| format!("[lib.rs]({})", entry.link);
+
+    // Newest year first, since that's the one being worked on
+    let years = advent_of_code.iter().rev().map(|Year {year, days: entries}| {
+        let mut days = Table::new(["Day", "Title", "Stars", "Examples", "Time", "Solution"]);
+
+        entries.iter().for_each(|entry| {
+            days.push([entry.number.to_string(), entry.title.clone(), "⭐".repeat(entry.stars), format!("{}/2", entry.examples), time(entry), link(entry)]);
+        });
+
+        let stars: usize = entries.iter().map(|entry| entry.stars).sum();
+        let examples: usize = entries.iter().map(|entry| entry.examples).sum();
+
+        format!("### Advent of Code {year}\n\n{stars} of {STARS} stars, {examples} parts with examples\n\n{}", days.markdown())
+    });
+
+    let mut problems = Table::new(["Problem", "Title", "Time", "Solution"]);
+
+    project_euler.iter().for_each(|entry| {
+        problems.push([entry.number.to_string(), entry.title.clone(), time(entry), link(entry)]);
+    });
+
+    [START.to_string(), "## Progress\n".to_string()]
+        .into_iter()
+        .chain(years)
+        .chain([
+            format!("### Project Euler\n\nSolved: {}\n", project_euler.len()),
+            problems.markdown(),
+            "Generated by `cargo run --release -p aoc -- readme`. Times are medians: parsing plus both parts for Advent of Code,".to_string(),
+            "the solution function under `euler bench` for Project Euler.".to_string(),
+            END.to_string(),
+        ])
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Swaps the generated section of `readme` for `progress`, or adds it after the title the first time
+pub fn splice(readme: &str, progress: &str) -> Result<String, String> {
+    match (readme.find(START), readme.find(END)) {
+        (Some(start), Some(end)) if start < end => Ok(format!("{}{progress}{}", &readme[..start], &readme[end + END.len()..])),
+        (None, None) => {
+            let (title, rest) = readme.split_once('\n').unwrap_or((readme, ""));
+
+            Ok(format!("{title}\n\n{progress}\n{rest}"))
+        },
+        _ => Err(format!("The README's {START} and {END} markers are out of order or one is missing")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(number: u32, title: &str, stars: usize, examples: usize, time: Option<Duration>) -> Entry {
+        Entry {number, title: title.to_string(), stars, examples, time, link: format!("Day-{number:02}/src/lib.rs")}
+    }
+
+    #[test]
+    fn test_split_directory() {
+        assert_eq!(split_directory("Day-09-Rope-Bridge"), Some((9, "Rope Bridge".to_string())));
+        assert_eq!(split_directory("Problem-001-Multiples-of-3-or-5"), Some((1, "Multiples of 3 or 5".to_string())));
+        assert_eq!(split_directory("Day-XX-Template"), None);
+    }
+
+    #[test]
+    fn test_render_and_splice() {
+        let year = |year, days| Year {year, days};
+
+        let progress = render(&[
+            year(2022, vec![entry(1, "Calorie Counting", 2, 2, Some(Duration::from_micros(41))), entry(2, "Rock Paper Scissors", 0, 2, None)]),
+            year(2023, vec![entry(1, "Trebuchet", 0, 0, None)]),
+        ], &[]);
+
+        // Day 2 has examples for both parts but no accepted answers, so it earns no stars
+        assert!(progress.contains("### Advent of Code 2022\n\n2 of 50 stars, 4 parts with examples"));
+        assert!(progress.find("### Advent of Code 2023\n\n0 of 50 stars, 0 parts with examples") < progress.find("### Advent of Code 2022"));
+        assert!(progress.contains("| 1 | Calorie Counting | ⭐⭐ | 2/2 | 41.00µs | [lib.rs](Day-01/src/lib.rs) |\n"));
+        assert!(progress.contains("| 2 | Rock Paper Scissors |  | 2/2 | - |"));
+
+        let readme = splice("# Competitive Programming\n\nRun things.\n", &progress).unwrap();
+
+        assert!(readme.starts_with(&format!("# Competitive Programming\n\n{START}\n## Progress")));
+        assert!(readme.ends_with(&format!("{END}\n\nRun things.\n")));
+
+        let updated = render(&[year(2022, vec![entry(1, "Calorie Counting", 2, 2, None)])], &[]);
+
+        assert_eq!(splice(&readme, &updated).unwrap(), format!("# Competitive Programming\n\n{updated}\n\nRun things.\n"));
+        assert!(splice(&format!("{END}\n{START}"), &updated).is_err());
+    }
+}
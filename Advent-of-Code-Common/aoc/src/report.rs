@@ -0,0 +1,158 @@
+use serde::{Serialize, Serializer};
+
+use aoc_core::Answer;
+
+use crate::answers::{hash_input, AnswerDb, Check};
+use crate::days::Day;
+use crate::execution::{Execution, Failure};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Regression,
+    NoInput,
+    ParseError,
+    Panicked,
+}
+
+// One line of `run --json`, for dashboards to collect and diff between commits. A day that could not be solved still
+// gets a line per part, with the reason in `error` and no answer
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub year: u32,
+    pub day: u32,
+    pub title: String,
+    pub part: u8,
+    pub status: Status,
+    #[serde(serialize_with = "answer_value")]
+    pub answer: Option<Answer>,
+    pub answer_type: Option<&'static str>,
+    pub verified: bool,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub input_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+// Just the value, since the type has its own field: a number, a string, or a picture's rows
+fn answer_value<S: Serializer>(answer: &Option<Answer>, serializer: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        None => serializer.serialize_none(),
+        Some(Answer::Integer(integer)) => serializer.serialize_i128(*integer),
+        Some(Answer::Text(text)) => serializer.serialize_str(text),
+        Some(Answer::Picture(picture)) => picture.serialize(serializer),
+    }
+}
+
+impl PartReport {
+    fn failed(day: &Day, part: u8, status: Status, input_hash: Option<String>, error: String) -> Self {
+        PartReport {
+            year: day.year,
+            day: day.day,
+            title: day.title(),
+            part,
+            status,
+            answer: None,
+            answer_type: None,
+            verified: false,
+            parse_ns: None,
+            solve_ns: None,
+            input_hash,
+            error: Some(error),
+        }
+    }
+}
+
+pub fn missing_input(day: &Day, parts: &[u8], err: &str) -> Vec<PartReport> {
+    parts
+        .iter()
+        .map(|&part| PartReport::failed(day, part, Status::NoInput, None, err.to_string()))
+        .collect()
+}
+
+pub fn reports(day: &Day, input: &str, parts: &[u8], result: Result<Execution, Failure>, answers: &AnswerDb) -> Vec<PartReport> {
+    let input_hash = hash_input(input);
+
+    let execution = match result {
+        Ok(execution) => execution,
+        Err(failure) => {
+            let status = match failure {
+                Failure::Parse(_) => Status::ParseError,
+                Failure::Panic {..} => Status::Panicked,
+            };
+
+            return parts
+                .iter()
+                .map(|&part| PartReport::failed(day, part, status, Some(input_hash.clone()), failure.to_string()))
+                .collect();
+        },
+    };
+
+    execution.parts
+        .into_iter()
+        .map(|run| {
+            let check = answers.check(day.year, day.day, run.part, &input_hash, &run.answer);
+
+            let (status, error) = match &check {
+                Check::Regression(expected) => (Status::Regression, Some(format!("{expected} was verified for this input"))),
+                _ => (Status::Ok, None),
+            };
+
+            PartReport {
+                year: day.year,
+                day: day.day,
+                title: day.title(),
+                part: run.part,
+                status,
+                answer_type: Some(run.answer.kind()),
+                answer: Some(run.answer),
+                verified: check == Check::Verified,
+                parse_ns: Some(execution.parse.as_nanos() as u64),
+                solve_ns: Some(run.duration.as_nanos() as u64),
+                input_hash: Some(input_hash.clone()),
+                error,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::Path;
+
+    use crate::days;
+    use crate::execution::execute_all;
+
+    #[test]
+    fn test_reports() {
+        let day = days::find(2022, 1).unwrap();
+        let answers = AnswerDb::load(Path::new("no-such-answers.json")).unwrap();
+
+        let input = "1000\n2000\n\n3000\n";
+
+        let run = |input: &str, parts: &[u8]| {
+            let result = execute_all(&[(day, input)], parts, 1).remove(0);
+
+            reports(day, input, parts, result, &answers)
+        };
+
+        let report = run(input, &[1]).remove(0);
+
+        assert_eq!((report.status, report.answer_type, report.verified), (Status::Ok, Some("integer"), false));
+        assert_eq!(report.input_hash, Some(hash_input(input)));
+
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["answer"], 3000);
+        assert_eq!(json["status"], "ok");
+        assert_eq!(json["error"], serde_json::Value::Null);
+
+        let reports = run("1000\nabc\n", &[1, 2]);
+
+        assert_eq!(reports.len(), 2);
+        assert!(reports.iter().all(|report| report.status == Status::ParseError && report.answer.is_none()));
+    }
+}
@@ -0,0 +1,219 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::days::{workspace_directory, year_directory};
+
+// Shared by every year, relative to the workspace
+const TEMPLATE: &str = "Advent-of-Code-Common/Day-00-Template";
+
+// "monkey in the middle" -> "Day-11-Monkey-In-The-Middle"
+pub fn directory_name(day: u32, title: &str) -> String {
+    let words: Vec<String> = title
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars().filter(|c| c.is_alphanumeric());
+
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .filter(|word: &String| !word.is_empty())
+        .collect();
+
+    format!("Day-{day:02}-{}", words.join("-"))
+}
+
+// 2022's crates were named before there was a second year, so only later years carry it in the name
+pub fn package_name(year: u32, day: u32) -> String {
+    if year == 2022 {format!("day-{day:02}")} else {format!("day-{year}-{day:02}")}
+}
+
+fn render(template: &str, year: u32, day: u32) -> String {
+    let package = package_name(year, day);
+
+    template
+        .replace("day-00", &package)
+        .replace("day_00", &package.replace('-', "_"))
+        .replace("Day00", &format!("Day{day:02}"))
+}
+
+// Adds `entry` to a list of lines that are each keyed by a year and day, keeping the list in that order
+fn insert_entry(text: &str, key: (u32, u32), entry: &str, entry_key: impl Fn(&str) -> Option<(u32, u32)>) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+
+    let entries: Vec<(usize, (u32, u32))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| entry_key(line).map(|k| (i, k)))
+        .collect();
+
+    if entries.iter().any(|&(_, k)| k == key) {
+        return Err(format!("Day {} of {} is already registered", key.1, key.0));
+    }
+
+    let position = match (entries.iter().rev().find(|&&(_, k)| k < key), entries.first()) {
+        (Some(&(i, _)), _) => i + 1,
+        (None, Some(&(i, _))) => i,
+        (None, None) => return Err("Could not find any registered days to add the new one next to".to_string()),
+    };
+
+    let mut lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+
+    lines.insert(position, entry.to_string());
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// "    2022, 9 => day_09::Day09, ..." -> (2022, 9)
+fn registry_key(line: &str) -> Option<(u32, u32)> {
+    let (key, solution) = line.trim().split_once(" => ")?;
+    let (year, day) = key.split_once(", ")?;
+
+    if solution.starts_with("day_") {Some((year.parse().ok()?, day.parse().ok()?))} else {None}
+}
+
+// "day-09 = ..." -> (2022, 9) and "day-2023-05 = ..." -> (2023, 5)
+fn manifest_key(line: &str) -> Option<(u32, u32)> {
+    let name = line.strip_prefix("day-")?.split_once(' ')?.0;
+
+    match name.split_once('-') {
+        Some((year, day)) => Some((year.parse().ok()?, day.parse().ok()?)),
+        None => Some((2022, name.parse().ok()?)),
+    }
+}
+
+pub fn register(registry: &str, manifest: &str, year: u32, day: u32, directory: &str) -> Result<(String, String), String> {
+    let package = package_name(year, day);
+
+    let registry = insert_entry(registry, (year, day), &format!("    {year}, {day} => {}::Day{day:02}, \"{directory}\";", package.replace('-', "_")), registry_key)?;
+    let manifest = insert_entry(manifest, (year, day), &format!("{package} = {{ path = \"../../Advent-of-Code-{year}/{directory}\" }}"), manifest_key)?;
+
+    Ok((registry, manifest))
+}
+
+fn copy_template(from: &Path, to: &Path, year: u32, day: u32) -> io::Result<()> {
+    fs::create_dir(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != "target" {
+                copy_template(&entry.path(), &target, year, day)?;
+            }
+        } else {
+            fs::write(target, render(&fs::read_to_string(entry.path())?, year, day))?;
+        }
+    }
+
+    Ok(())
+}
+
+// Creates the day under Advent-of-Code-<year>, starting the year's directory if this is its first day
+pub fn new_day(year: u32, day: u32, title: &str) -> Result<String, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {day} is not an Advent of Code day"));
+    }
+
+    if year < 2015 {
+        return Err(format!("There was no Advent of Code in {year}"));
+    }
+
+    let year_directory = year_directory(year);
+
+    fs::create_dir_all(&year_directory).map_err(|err| format!("Could not create {}: {err}", year_directory.display()))?;
+
+    let prefix = format!("Day-{day:02}-");
+
+    let existing = fs::read_dir(&year_directory)
+        .map_err(|err| format!("Could not read {}: {err}", year_directory.display()))?
+        .filter_map(Result::ok)
+        .find(|entry| entry.file_name().to_string_lossy().starts_with(&prefix));
+
+    if let Some(entry) = existing {
+        return Err(format!("Day {day} of {year} already exists at {}", entry.path().display()));
+    }
+
+    let directory = directory_name(day, title);
+
+    let runner = workspace_directory().join("Advent-of-Code-Common/aoc");
+    let registry_path = runner.join("src/days.rs");
+    let manifest_path = runner.join("Cargo.toml");
+
+    let read = |path: &Path| fs::read_to_string(path).map_err(|err| format!("Could not read {}: {err}", path.display()));
+
+    // Work out both edits before touching anything, so a failure doesn't leave a half registered day behind
+    let (registry, manifest) = register(&read(&registry_path)?, &read(&manifest_path)?, year, day, &directory)?;
+
+    let path = year_directory.join(&directory);
+
+    copy_template(&workspace_directory().join(TEMPLATE), &path, year, day).map_err(|err| format!("Could not create {}: {err}", path.display()))?;
+
+    let test_input = path.join("test_input.txt");
+
+    if !test_input.exists() {
+        fs::write(&test_input, "").map_err(|err| format!("Could not create {}: {err}", test_input.display()))?;
+    }
+
+    let write = |path: &Path, contents: String| fs::write(path, contents).map_err(|err| format!("Could not write {}: {err}", path.display()));
+
+    write(&registry_path, registry)?;
+    write(&manifest_path, manifest)?;
+
+    Ok(directory)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "days! {\n    2022, 1 => day_01::Day01, \"Day-01-Calorie-Counting\";\n    2022, 14 => day_14::Day14, \"Day-14-Regolith-Reservoir\";\n}\n";
+    const MANIFEST: &str = "[dependencies]\naoc-core = { path = \"../aoc-core\" }\nday-01 = { path = \"../../Advent-of-Code-2022/Day-01-Calorie-Counting\" }\nday-14 = { path = \"../../Advent-of-Code-2022/Day-14-Regolith-Reservoir\" }\n";
+
+    #[test]
+    fn test_directory_name() {
+        assert_eq!(directory_name(15, "beacon exclusion zone"), "Day-15-Beacon-Exclusion-Zone");
+        assert_eq!(directory_name(7, "No Space Left On Device"), "Day-07-No-Space-Left-On-Device");
+        assert_eq!(directory_name(21, "Monkey Math!"), "Day-21-Monkey-Math");
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(render("name = \"day-00\"\nlet result = Day00::part_1(..);", 2022, 15), "name = \"day-15\"\nlet result = Day15::part_1(..);");
+        assert_eq!(render("name = \"day-00\"\nuse day_00::Day00;", 2023, 5), "name = \"day-2023-05\"\nuse day_2023_05::Day05;");
+    }
+
+    #[test]
+    fn test_register() {
+        let (registry, manifest) = register(REGISTRY, MANIFEST, 2022, 9, "Day-09-Rope-Bridge").unwrap();
+
+        assert_eq!(registry.lines().nth(2), Some("    2022, 9 => day_09::Day09, \"Day-09-Rope-Bridge\";"));
+        assert_eq!(manifest.lines().nth(3), Some("day-09 = { path = \"../../Advent-of-Code-2022/Day-09-Rope-Bridge\" }"));
+
+        let (registry, _) = register(REGISTRY, MANIFEST, 2022, 15, "Day-15-Beacon-Exclusion-Zone").unwrap();
+
+        assert_eq!(registry.lines().nth(3), Some("    2022, 15 => day_15::Day15, \"Day-15-Beacon-Exclusion-Zone\";"));
+    }
+
+    #[test]
+    fn test_register_new_year() {
+        let (registry, manifest) = register(REGISTRY, MANIFEST, 2023, 1, "Day-01-Trebuchet").unwrap();
+
+        assert_eq!(registry.lines().nth(3), Some("    2023, 1 => day_2023_01::Day01, \"Day-01-Trebuchet\";"));
+        assert_eq!(manifest.lines().nth(4), Some("day-2023-01 = { path = \"../../Advent-of-Code-2023/Day-01-Trebuchet\" }"));
+
+        let (registry, manifest) = register(&registry, &manifest, 2022, 15, "Day-15-Beacon-Exclusion-Zone").unwrap();
+
+        assert_eq!(registry.lines().nth(3), Some("    2022, 15 => day_15::Day15, \"Day-15-Beacon-Exclusion-Zone\";"));
+        assert_eq!(manifest.lines().nth(4), Some("day-15 = { path = \"../../Advent-of-Code-2022/Day-15-Beacon-Exclusion-Zone\" }"));
+    }
+
+    #[test]
+    fn test_register_existing_day() {
+        assert_eq!(register(REGISTRY, MANIFEST, 2022, 14, "Day-14-Anything"), Err("Day 14 of 2022 is already registered".to_string()));
+    }
+}
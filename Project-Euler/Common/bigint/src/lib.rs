@@ -0,0 +1,280 @@
+mod limbs;
+mod ops;
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+// An unsigned integer of any size, as little-endian base 2^32 limbs with no high zero limbs, so zero has none
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+// Formatting and parsing go nine decimal digits at a time, the most that fit in a limb
+const CHUNK: u32 = 1_000_000_000;
+const CHUNK_DIGITS: usize = 9;
+
+impl BigUint {
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        limbs::normalize(&mut limbs);
+
+        BigUint {limbs}
+    }
+
+    pub fn zero() -> Self {
+        BigUint::default()
+    }
+
+    pub fn one() -> Self {
+        BigUint {limbs: vec![1]}
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.limbs.last().map_or(0, |top| self.limbs.len() as u64 * 32 - top.leading_zeros() as u64)
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {return None}
+
+        Some(self.limbs.iter().rev().fold(0, |n, &limb| (n << 32) | limb as u128))
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut result = BigUint::one();
+        let mut base = self.clone();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+
+            exponent >>= 1;
+
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    pub fn div_rem_u32(&self, divisor: u32) -> (Self, u32) {
+        let (quotient, remainder) = limbs::div_rem_small(&self.limbs, divisor);
+
+        (BigUint::from_limbs(quotient), remainder)
+    }
+
+    pub fn div_rem(&self, divisor: &BigUint) -> (Self, Self) {
+        let (quotient, remainder) = limbs::div_rem(&self.limbs, &divisor.limbs);
+
+        (BigUint::from_limbs(quotient), BigUint::from_limbs(remainder))
+    }
+
+    // Decimal digits, most significant first
+    pub fn digits(&self) -> impl Iterator<Item = u8> {
+        self.to_string().into_bytes().into_iter().map(|byte| byte - b'0')
+    }
+}
+
+impl From<u32> for BigUint {
+    fn from(n: u32) -> Self {
+        BigUint::from_limbs(vec![n])
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> Self {
+        BigUint::from(n as u128)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(n: u128) -> Self {
+        BigUint::from_limbs((0..4).map(|i| (n >> (32 * i)) as u32).collect())
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        limbs::cmp(&self.limbs, &other.limbs)
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut chunks = vec![];
+        let mut rest = self.limbs.clone();
+
+        while !rest.is_empty() {
+            let (quotient, chunk) = limbs::div_rem_small(&rest, CHUNK);
+
+            chunks.push(chunk);
+            rest = quotient;
+
+            limbs::normalize(&mut rest);
+        }
+
+        let mut chunks = chunks.iter().rev();
+
+        let digits = match chunks.next() {
+            None => "0".to_string(),
+            Some(first) => chunks.fold(first.to_string(), |digits, chunk| digits + &format!("{chunk:0CHUNK_DIGITS$}")),
+        };
+
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseBigUintError;
+
+impl fmt::Display for ParseBigUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a non-empty string of decimal digits")
+    }
+}
+
+impl std::error::Error for ParseBigUintError {}
+
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ParseBigUintError);
+        }
+
+        // The first chunk takes whatever is left over so the rest are all full
+        let first = match s.len() % CHUNK_DIGITS {
+            0 => CHUNK_DIGITS,
+            n => n,
+        };
+
+        let chunks = std::iter::once(&s[..first]).chain(s.as_bytes()[first..].chunks(CHUNK_DIGITS).map(|chunk| std::str::from_utf8(chunk).unwrap()));
+
+        let limbs = chunks.fold(vec![], |limbs: Vec<u32>, chunk| {
+            let scale = 10u32.pow(chunk.len() as u32);
+
+            limbs::add(&limbs::mul_small(&limbs, scale), &[chunk.parse().unwrap()])
+        });
+
+        Ok(BigUint::from_limbs(limbs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    fn big(s: &str) -> BigUint {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_format_and_parse() {
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(BigUint::from(1_000_000_000u32).to_string(), "1000000000");
+        assert_eq!(big("000123").to_string(), "123");
+        assert_eq!(format!("{:>6}", BigUint::from(42u32)), "    42");
+        assert_eq!("".parse::<BigUint>(), Err(ParseBigUintError));
+        assert_eq!("12a".parse::<BigUint>(), Err(ParseBigUintError));
+        assert_eq!("-1".parse::<BigUint>(), Err(ParseBigUintError));
+    }
+
+    #[test]
+    fn test_beyond_u128() {
+        assert_eq!(BigUint::from(2u32).pow(100).to_string(), "1267650600228229401496703205376");
+        assert_eq!(BigUint::from(2u32).pow(1000).bits(), 1001);
+        assert_eq!(BigUint::from(2u32).pow(1000).digits().count(), 302);
+
+        let factorial: BigUint = (1..=30u32).map(BigUint::from).product();
+
+        assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+        assert_eq!(BigUint::from(u128::MAX).to_u128(), Some(u128::MAX));
+        assert_eq!((BigUint::from(u128::MAX) + BigUint::one()).to_u128(), None);
+
+        let n = big("123456789012345678901234567890123456789012345678901234567890");
+        let d = big("987654321098765432109876543210");
+        let (q, r) = n.div_rem(&d);
+
+        assert!(r < d);
+        assert_eq!(&q * &d + &r, n);
+        assert_eq!(q.to_string(), "124999998860937500014238281249");
+    }
+
+    #[test]
+    fn test_karatsuba() {
+        let a = BigUint::from(3u32).pow(2000);
+        let b = BigUint::from(7u32).pow(1500);
+
+        assert!(a.limbs.len() > 64 && b.limbs.len() > 64);
+        assert_eq!(BigUint::from_limbs(limbs::karatsuba(&a.limbs, &b.limbs)), BigUint::from(21u32).pow(1500) * BigUint::from(3u32).pow(500));
+        assert_eq!((&a * &b).div_rem(&b), (a.clone(), BigUint::zero()));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_u128(a: u64, b: u64, c: u128) {
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+
+            prop_assert_eq!((&x + &y).to_u128(), Some(a as u128 + b as u128));
+            prop_assert_eq!((&x * &y).to_u128(), Some(a as u128 * b as u128));
+            prop_assert_eq!(x.cmp(&y), a.cmp(&b));
+            prop_assert_eq!(BigUint::from(c).to_u128(), Some(c));
+            prop_assert_eq!(BigUint::from(c).to_string(), c.to_string());
+            prop_assert_eq!(big(&c.to_string()), BigUint::from(c));
+
+            if a >= b {
+                prop_assert_eq!((&x - &y).to_u128(), Some((a - b) as u128));
+            }
+        }
+
+        #[test]
+        fn prop_division(a: u128, b in 1..u128::MAX, small in 1..u32::MAX) {
+            let (q, r) = BigUint::from(a).div_rem(&BigUint::from(b));
+
+            prop_assert_eq!((q.to_u128(), r.to_u128()), (Some(a / b), Some(a % b)));
+
+            let (q, r) = BigUint::from(a).div_rem_u32(small);
+
+            prop_assert_eq!((q.to_u128(), r), (Some(a / small as u128), (a % small as u128) as u32));
+        }
+
+        #[test]
+        fn prop_pow(base in 0..100u32, exponent in 0..19u32) {
+            prop_assert_eq!(BigUint::from(base).pow(exponent).to_u128(), Some((base as u128).pow(exponent)));
+        }
+
+        // Large operands, against the schoolbook product and the division that should undo it
+        #[test]
+        fn prop_large(a in prop::collection::vec(any::<u32>(), 1..150), b in prop::collection::vec(any::<u32>(), 1..150)) {
+            let (x, y) = (BigUint::from_limbs(a), BigUint::from_limbs(b));
+
+            prop_assume!(!y.is_zero());
+
+            let product = &x * &y;
+
+            prop_assert_eq!(&product, &BigUint::from_limbs(limbs::schoolbook(&x.limbs, &y.limbs)));
+            prop_assert_eq!(product.div_rem(&y), (x.clone(), BigUint::zero()));
+
+            let (q, r) = x.div_rem(&y);
+
+            prop_assert!(r < y);
+            prop_assert_eq!(&(&q * &y) + &r, x.clone());
+            prop_assert_eq!(big(&x.to_string()), x);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+// Arithmetic on little-endian base 2^32 limbs. Inputs may have high zero limbs, outputs are trimmed by the caller
+use std::cmp::Ordering;
+
+// Below this many limbs in the shorter operand, splitting costs more than the schoolbook multiplications it saves
+const KARATSUBA_THRESHOLD: usize = 32;
+
+pub fn trim(limbs: &[u32]) -> &[u32] {
+    let len = limbs.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1);
+
+    &limbs[..len]
+}
+
+pub fn normalize(limbs: &mut Vec<u32>) {
+    let len = trim(limbs).len();
+
+    limbs.truncate(len);
+}
+
+pub fn cmp(a: &[u32], b: &[u32]) -> Ordering {
+    let (a, b) = (trim(a), trim(b));
+
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+pub fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() {(a, b)} else {(b, a)};
+
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+
+    for (i, &limb) in long.iter().enumerate() {
+        let s = limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+
+        sum.push(s as u32);
+        carry = s >> 32;
+    }
+
+    sum.push(carry as u32);
+
+    sum
+}
+
+// a - b, for a at least b
+pub fn sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+
+    for (i, &limb) in a.iter().enumerate() {
+        let d = limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+
+        difference.push(d as u32);
+        borrow = (d < 0) as i64;
+    }
+
+    debug_assert!(borrow == 0 && trim(b).len() <= a.len(), "subtraction underflow");
+
+    difference
+}
+
+// Adds `x` into `target` starting `offset` limbs up, which has to have room for the result
+fn add_at(target: &mut [u32], x: &[u32], offset: usize) {
+    let mut carry = 0;
+
+    for (i, target) in target[offset..].iter_mut().enumerate() {
+        if i >= x.len() && carry == 0 {break}
+
+        let s = *target as u64 + x.get(i).copied().unwrap_or(0) as u64 + carry;
+
+        *target = s as u32;
+        carry = s >> 32;
+    }
+}
+
+pub fn schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0; a.len() + b.len()];
+
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+
+        for (j, &y) in b.iter().enumerate() {
+            let p = x as u64 * y as u64 + product[i + j] as u64 + carry;
+
+            product[i + j] = p as u32;
+            carry = p >> 32;
+        }
+
+        product[i + b.len()] = carry as u32;
+    }
+
+    product
+}
+
+// Karatsuba: with a = a1·B + a0 and b = b1·B + b0, the middle term a1·b0 + a0·b1 is (a0 + a1)(b0 + b1) - a0·b0 - a1·b1,
+// so three half size products do the work of four
+pub fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = (trim(a), trim(b));
+
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+
+    karatsuba(a, b)
+}
+
+pub fn karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let half = a.len().min(b.len()) / 2;
+
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half);
+
+    let low = mul(a0, b0);
+    let high = mul(a1, b1);
+    let cross = mul(&add(a0, a1), &add(b0, b1));
+
+    let middle = sub(&sub(&cross, &low), &high);
+
+    let mut product = vec![0; a.len() + b.len() + 1];
+
+    add_at(&mut product, trim(&low), 0);
+    add_at(&mut product, trim(&middle), half);
+    add_at(&mut product, trim(&high), 2 * half);
+
+    product
+}
+
+pub fn mul_small(a: &[u32], m: u32) -> Vec<u32> {
+    let mut product = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+
+    for &limb in a {
+        let p = limb as u64 * m as u64 + carry;
+
+        product.push(p as u32);
+        carry = p >> 32;
+    }
+
+    product.push(carry as u32);
+
+    product
+}
+
+pub fn div_rem_small(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    assert!(divisor != 0, "division by zero");
+
+    let mut quotient = vec![0; a.len()];
+    let mut remainder = 0u64;
+
+    for (i, &limb) in a.iter().enumerate().rev() {
+        let n = (remainder << 32) | limb as u64;
+
+        quotient[i] = (n / divisor as u64) as u32;
+        remainder = n % divisor as u64;
+    }
+
+    (quotient, remainder as u32)
+}
+
+fn shift_left(a: &[u32], shift: u32) -> Vec<u32> {
+    let mut shifted = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+
+    for &limb in a {
+        let s = ((limb as u64) << shift) | carry;
+
+        shifted.push(s as u32);
+        carry = s >> 32;
+    }
+
+    shifted.push(carry as u32);
+
+    shifted
+}
+
+fn shift_right(a: &[u32], shift: u32) -> Vec<u32> {
+    (0..a.len())
+        .map(|i| {
+            let high = a.get(i + 1).copied().unwrap_or(0) as u64;
+
+            ((((high << 32) | a[i] as u64) >> shift) & 0xffff_ffff) as u32
+        })
+        .collect()
+}
+
+// Knuth's algorithm D. Both are scaled so the divisor's top limb has its high bit set, which keeps each estimated
+// quotient limb at most two too large; the estimate is corrected against the top two divisor limbs, and the rare
+// remaining overshoot is caught when the subtraction goes negative and undone by adding the divisor back
+pub fn div_rem(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let (a, b) = (trim(a), trim(b));
+
+    assert!(!b.is_empty(), "division by zero");
+
+    if cmp(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+
+    if b.len() == 1 {
+        let (quotient, remainder) = div_rem_small(a, b[0]);
+
+        return (quotient, vec![remainder]);
+    }
+
+    let shift = b[b.len() - 1].leading_zeros();
+
+    let v = &shift_left(b, shift)[..b.len()];
+    let mut u = shift_left(a, shift);
+
+    let n = v.len();
+    let m = u.len() - n - 1;
+
+    let (top, second) = (v[n - 1] as u64, v[n - 2] as u64);
+
+    let mut quotient = vec![0; m + 1];
+
+    for j in (0..=m).rev() {
+        let numerator = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+
+        let mut q = numerator / top;
+        let mut r = numerator % top;
+
+        while q >= 1 << 32 || q * second > ((r << 32) | u[j + n - 2] as u64) {
+            q -= 1;
+            r += top;
+
+            if r >= 1 << 32 {break}
+        }
+
+        let mut borrow = 0;
+        let mut carry = 0;
+
+        for i in 0..n {
+            let p = q * v[i] as u64 + carry;
+            let t = u[i + j] as i64 - borrow - (p & 0xffff_ffff) as i64;
+
+            carry = p >> 32;
+            u[i + j] = t as u32;
+            borrow = (t < 0) as i64;
+        }
+
+        let t = u[j + n] as i64 - borrow - carry as i64;
+
+        u[j + n] = t as u32;
+
+        if t < 0 {
+            q -= 1;
+
+            let mut carry = 0;
+
+            for i in 0..n {
+                let s = u[i + j] as u64 + v[i] as u64 + carry;
+
+                u[i + j] = s as u32;
+                carry = s >> 32;
+            }
+
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+
+        quotient[j] = q as u32;
+    }
+
+    (quotient, shift_right(&u[..n], shift))
+}
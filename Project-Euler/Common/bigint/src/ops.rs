@@ -0,0 +1,128 @@
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Rem, Sub, SubAssign};
+
+use crate::{limbs, BigUint};
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        BigUint::from_limbs(limbs::add(&self.limbs, &other.limbs))
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        assert!(*self >= *other, "subtraction underflow: {self} - {other}");
+
+        BigUint::from_limbs(limbs::sub(&self.limbs, &other.limbs))
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        BigUint::from_limbs(limbs::mul(&self.limbs, &other.limbs))
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+// The owned and mixed forms all borrow their way to the ones above
+macro_rules! forward {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl $trait<BigUint> for BigUint {
+                type Output = BigUint;
+
+                fn $method(self, other: BigUint) -> BigUint {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&BigUint> for BigUint {
+                type Output = BigUint;
+
+                fn $method(self, other: &BigUint) -> BigUint {
+                    (&self).$method(other)
+                }
+            }
+
+            impl $trait<BigUint> for &BigUint {
+                type Output = BigUint;
+
+                fn $method(self, other: BigUint) -> BigUint {
+                    self.$method(&other)
+                }
+            }
+        )*
+    };
+}
+
+forward!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+macro_rules! assign {
+    ($($trait:ident $method:ident $op:ident),*) => {
+        $(
+            impl $trait<&BigUint> for BigUint {
+                fn $method(&mut self, other: &BigUint) {
+                    *self = (&*self).$op(other);
+                }
+            }
+
+            impl $trait<BigUint> for BigUint {
+                fn $method(&mut self, other: BigUint) {
+                    *self = (&*self).$op(&other);
+                }
+            }
+        )*
+    };
+}
+
+assign!(AddAssign add_assign add, SubAssign sub_assign sub, MulAssign mul_assign mul);
+
+// Small factors skip the general multiplication, which is what factorials and digit-by-digit products mostly need
+impl Mul<u32> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: u32) -> BigUint {
+        BigUint::from_limbs(limbs::mul_small(&self.limbs, other))
+    }
+}
+
+impl Mul<u32> for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: u32) -> BigUint {
+        &self * other
+    }
+}
+
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::zero(), |sum, n| sum + n)
+    }
+}
+
+impl Product for BigUint {
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::one(), |product, n| product * n)
+    }
+}
@@ -0,0 +1,126 @@
+use bigint::BigUint;
+use number_theory::{gcd, mod_pow, mul_mod};
+
+// n choose k, one factor at a time: after step i the running value is C(n, i + 1), so it only overflows if the answer
+// does. Each step divides out the common factor first, which is what keeps the intermediate product that small
+pub fn binomial(n: u64, k: u64) -> u128 {
+    if k > n {return 0}
+
+    let k = k.min(n - k);
+
+    (0..k).fold(1u128, |c, i| {
+        let (numerator, denominator) = ((n - i) as u128, (i + 1) as u128);
+
+        let g = gcd((c % denominator) as u64, denominator as u64) as u128;
+
+        (c / g).checked_mul(numerator / (denominator / g)).expect("binomial coefficient does not fit in a u128")
+    })
+}
+
+pub fn binomial_big(n: u64, k: u64) -> BigUint {
+    if k > n {return BigUint::zero()}
+
+    let k = k.min(n - k);
+
+    (0..k).fold(BigUint::one(), |c, i| (c * BigUint::from(n - i)).div_rem(&BigUint::from(i + 1)).0)
+}
+
+// Binomials modulo a prime p from tables of factorials and their inverses below p, with Lucas' theorem splitting
+// n and k into base p digits so n can be as large as it likes once the tables cover every digit
+pub struct ModBinomial {
+    p: u64,
+    limit: u64,
+    factorials: Vec<u64>,
+    inverses: Vec<u64>,
+}
+
+impl ModBinomial {
+    // Tables up to `limit` or p, whichever is smaller, as no digit goes past either
+    pub fn new(limit: u64, p: u64) -> Self {
+        let size = (limit + 1).min(p) as usize;
+
+        let mut factorials = vec![1 % p; size];
+
+        (1..size).for_each(|i| factorials[i] = mul_mod(factorials[i - 1], i as u64, p));
+
+        let mut inverses = vec![1 % p; size];
+
+        if let Some(&last) = factorials.last() {
+            inverses[size - 1] = mod_pow(last, p - 2, p);
+        }
+
+        (1..size).rev().for_each(|i| inverses[i - 1] = mul_mod(inverses[i], i as u64, p));
+
+        // With every digit below p in the tables there is no limit on n
+        let limit = if size as u64 == p {u64::MAX} else {limit};
+
+        ModBinomial {p, limit, factorials, inverses}
+    }
+
+    fn small(&self, n: u64, k: u64) -> u64 {
+        if k > n {return 0}
+
+        let (n, k) = (n as usize, k as usize);
+
+        mul_mod(mul_mod(self.factorials[n], self.inverses[k], self.p), self.inverses[n - k], self.p)
+    }
+
+    pub fn binomial(&self, mut n: u64, mut k: u64) -> u64 {
+        assert!(n <= self.limit, "C({n}, {k}) is past the limit of {} the tables were built for", self.limit);
+
+        let mut result = 1 % self.p;
+
+        while k > 0 {
+            result = mul_mod(result, self.small(n % self.p, k % self.p), self.p);
+
+            (n, k) = (n / self.p, k / self.p);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binomial() {
+        let mut row = vec![1u128];
+
+        for n in 0..130u64 {
+            row.iter().enumerate().for_each(|(k, &c)| assert_eq!(binomial(n, k as u64), c, "C({n}, {k})"));
+
+            assert_eq!(binomial(n, n + 1), 0);
+
+            row = (0..=row.len()).map(|k| if k == 0 || k == row.len() {1} else {row[k - 1] + row[k]}).collect();
+        }
+
+        // The 20x20 lattice paths, and a central binomial close to the top of u128
+        assert_eq!(binomial(40, 20), 137_846_528_820);
+        assert_eq!(binomial(130, 65), 95_067_625_827_960_698_145_584_333_020_095_113_100);
+        assert_eq!(binomial_big(130, 65).to_u128(), Some(binomial(130, 65)));
+        assert_eq!(binomial_big(200, 100).to_string(), "90548514656103281165404177077484163874504589675413336841320");
+    }
+
+    #[test]
+    fn test_mod_binomial() {
+        for p in [2, 3, 7, 13, 1_000_000_007] {
+            let table = ModBinomial::new(60, p);
+
+            for n in 0..60 {
+                for k in 0..=n + 1 {
+                    assert_eq!(table.binomial(n, k) as u128, binomial(n, k) % p as u128, "C({n}, {k}) mod {p}");
+                }
+            }
+        }
+
+        assert_eq!(ModBinomial::new(10, 7).binomial(1000, 500), (binomial_big(1000, 500).div_rem_u32(7).1) as u64);
+    }
+
+    #[test]
+    #[should_panic(expected = "past the limit of 60")]
+    fn test_mod_binomial_past_limit() {
+        ModBinomial::new(60, 1_000_000_007).binomial(61, 2);
+    }
+}
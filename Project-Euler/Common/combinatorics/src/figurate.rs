@@ -0,0 +1,68 @@
+pub fn triangular(n: u64) -> u64 {
+    n * (n + 1) / 2
+}
+
+pub fn pentagonal(n: u64) -> u64 {
+    n * (3 * n).saturating_sub(1) / 2
+}
+
+pub fn hexagonal(n: u64) -> u64 {
+    n * (2 * n).saturating_sub(1)
+}
+
+// Each test inverts the formula: x is the n-th number when the discriminant is a perfect square s and n comes out
+// whole, all in u128 so any u64 is safe
+fn square_root(x: u128) -> Option<u128> {
+    let s = x.isqrt();
+
+    (s * s == x).then_some(s)
+}
+
+// T(n) = x for n = (s - 1) / 2 with s² = 8x + 1
+pub fn is_triangular(x: u64) -> bool {
+    x > 0 && square_root(8 * x as u128 + 1).is_some()
+}
+
+// P(n) = x for n = (s + 1) / 6 with s² = 24x + 1
+pub fn is_pentagonal(x: u64) -> bool {
+    x > 0 && square_root(24 * x as u128 + 1).is_some_and(|s| (s + 1).is_multiple_of(6))
+}
+
+// H(n) = x for n = (s + 1) / 4 with s² = 8x + 1
+pub fn is_hexagonal(x: u64) -> bool {
+    x > 0 && square_root(8 * x as u128 + 1).is_some_and(|s| (s + 1).is_multiple_of(4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_predicates() {
+        let limit = 100_000;
+
+        let sequence = |f: fn(u64) -> u64| (1..).map(f).take_while(|&x| x < limit).collect::<HashSet<u64>>();
+
+        let (triangles, pentagons, hexagons) = (sequence(triangular), sequence(pentagonal), sequence(hexagonal));
+
+        (0..limit).for_each(|x| {
+            assert_eq!(is_triangular(x), triangles.contains(&x), "{x}");
+            assert_eq!(is_pentagonal(x), pentagons.contains(&x), "{x}");
+            assert_eq!(is_hexagonal(x), hexagons.contains(&x), "{x}");
+        });
+
+        // T(285) = P(165) = H(143), and every hexagonal number is triangular
+        assert_eq!((triangular(285), pentagonal(165), hexagonal(143)), (40755, 40755, 40755));
+        assert!(hexagons.is_subset(&triangles));
+        assert!(is_pentagonal(pentagonal(2_000_000_000)) && is_hexagonal(hexagonal(2_000_000_000)));
+        assert!(!is_pentagonal(pentagonal(2_000_000_000) + 1));
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!((triangular(0), pentagonal(0), hexagonal(0)), (0, 0, 0));
+        assert!(!is_triangular(0) && !is_pentagonal(0) && !is_hexagonal(0));
+    }
+}
@@ -0,0 +1,11 @@
+mod binomial;
+mod figurate;
+mod partition;
+mod permutation;
+mod sequence;
+
+pub use binomial::{binomial, binomial_big, ModBinomial};
+pub use figurate::{hexagonal, is_hexagonal, is_pentagonal, is_triangular, pentagonal, triangular};
+pub use partition::{partition_counts, ways_to_make};
+pub use permutation::{next_permutation, nth_permutation};
+pub use sequence::{fibonacci, fibonacci_big, fibonacci_mod, Collatz};
@@ -0,0 +1,61 @@
+// p(n) for every n up to `limit`, the ways of writing n as a sum of positive integers, by Euler's pentagonal number
+// theorem: p(n) = p(n - 1) + p(n - 2) - p(n - 5) - p(n - 7) + ..., stepping through the generalized pentagonal numbers
+pub fn partition_counts(limit: usize) -> Vec<u128> {
+    let mut counts = vec![1u128];
+
+    for n in 1..=limit {
+        let (mut added, mut removed) = (0, 0);
+
+        for k in 1.. {
+            let pentagonals = [k * (3 * k - 1) / 2, k * (3 * k + 1) / 2];
+
+            if pentagonals[0] > n {break}
+
+            let sum: u128 = pentagonals.iter().filter(|&&g| g <= n).map(|&g| counts[n - g]).sum();
+
+            if k % 2 == 1 {added += sum} else {removed += sum}
+        }
+
+        counts.push(added - removed);
+    }
+
+    counts
+}
+
+// The ways of making `total` from any number of each of `parts`, ignoring order, like coins making an amount
+pub fn ways_to_make(total: usize, parts: &[usize]) -> u128 {
+    let mut ways = vec![0u128; total + 1];
+
+    ways[0] = 1;
+
+    for &part in parts.iter().filter(|&&part| part > 0) {
+        (part..=total).for_each(|amount| ways[amount] += ways[amount - part]);
+    }
+
+    ways[total]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition_counts() {
+        let counts = partition_counts(200);
+
+        assert_eq!(counts[..12], [1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42, 56]);
+        assert_eq!(counts[100], 190_569_292);
+        assert_eq!(counts[200], 3_972_999_029_388);
+
+        // Every part allowed is the same question
+        (0..=60).for_each(|n| assert_eq!(ways_to_make(n, &(1..=n).collect::<Vec<usize>>()), counts[n], "{n}"));
+    }
+
+    #[test]
+    fn test_ways_to_make() {
+        assert_eq!(ways_to_make(10, &[2, 5, 3, 6]), 5);
+        assert_eq!(ways_to_make(0, &[7]), 1);
+        assert_eq!(ways_to_make(7, &[]), 0);
+        assert_eq!(ways_to_make(7, &[0, 7]), 1);
+    }
+}
@@ -0,0 +1,103 @@
+// Rearranges `items` into the next permutation in lexicographic order and returns true, or returns false and leaves
+// them in the first (sorted) order once they were at the last
+pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    let Some(pivot) = items.windows(2).rposition(|pair| pair[0] < pair[1]) else {
+        items.reverse();
+
+        return false;
+    };
+
+    let successor = items.iter().rposition(|item| *item > items[pivot]).unwrap();
+
+    items.swap(pivot, successor);
+    items[pivot + 1..].reverse();
+
+    true
+}
+
+// The k-th permutation (counting from 0) of `items` in lexicographic order, which has to be sorted, read off the
+// factorial number system: k / (n - 1)! picks the first item, and so on down. None when k is past the last one
+pub fn nth_permutation<T: Clone>(items: &[T], mut k: u128) -> Option<Vec<T>> {
+    let mut remaining = items.to_vec();
+
+    // 0! to (n - 1)!, None once they outgrow u128 and so any k picks the first of what remains
+    let factorials: Vec<Option<u128>> = (1..=remaining.len() as u128)
+        .scan(Some(1u128), |factorial, i| {
+            let current = *factorial;
+
+            *factorial = factorial.and_then(|factorial| factorial.checked_mul(i));
+
+            Some(current)
+        })
+        .collect();
+
+    let count = factorials.last().map_or(Some(1), |last| last.and_then(|last| last.checked_mul(remaining.len() as u128)));
+
+    if count.is_some_and(|count| k >= count) {
+        return None;
+    }
+
+    let permutation = factorials
+        .iter()
+        .rev()
+        .map(|&factorial| {
+            let index = factorial.map_or(0, |factorial| k / factorial) as usize;
+
+            if let Some(factorial) = factorial {
+                k %= factorial;
+            }
+
+            remaining.remove(index)
+        })
+        .collect();
+
+    Some(permutation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_permutation() {
+        let mut items = [1, 2, 2, 3];
+        let mut seen = vec![items];
+
+        while next_permutation(&mut items) {
+            seen.push(items);
+        }
+
+        assert_eq!(seen.len(), 12);
+        assert!(seen.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(items, [1, 2, 2, 3]);
+
+        let mut empty: [u8; 0] = [];
+
+        assert!(!next_permutation(&mut empty));
+    }
+
+    #[test]
+    fn test_nth_permutation() {
+        let digits: Vec<u8> = (0..=4).collect();
+        let mut items = digits.clone();
+
+        for k in 0..120 {
+            assert_eq!(nth_permutation(&digits, k), Some(items.clone()), "{k}");
+
+            next_permutation(&mut items);
+        }
+
+        assert_eq!(nth_permutation(&digits, 120), None);
+        assert_eq!(nth_permutation(&['a', 'b', 'c'], 3), Some(vec!['b', 'c', 'a']));
+        assert_eq!(nth_permutation::<u8>(&[], 0), Some(vec![]));
+        assert_eq!(nth_permutation::<u8>(&[], 1), None);
+
+        let letters: Vec<u8> = (0..40).collect();
+
+        // u128::MAX is past 34! but short of 35!, so the first five stay put and the sixth is the next one along
+        let last = nth_permutation(&letters, u128::MAX).unwrap();
+
+        assert_eq!(last[..6], [0, 1, 2, 3, 4, 6]);
+        assert_eq!(last.len(), 40);
+    }
+}
@@ -0,0 +1,137 @@
+use bigint::BigUint;
+
+// Fast doubling from (F(k), F(k + 1)), using F(2k) = F(k)(2F(k + 1) - F(k)) and F(2k + 1) = F(k)² + F(k + 1)², one bit
+// of n at a time from the top
+fn doubling<T: Clone>(n: u64, zero: T, one: T, double: impl Fn(&T, &T) -> (T, T), add: impl Fn(&T, &T) -> T) -> T {
+    let (mut a, mut b) = (zero, one);
+
+    for bit in (0..64 - n.leading_zeros()).rev() {
+        let (even, odd) = double(&a, &b);
+
+        (a, b) = if n >> bit & 1 == 1 {let next = add(&even, &odd); (odd, next)} else {(even, odd)};
+    }
+
+    a
+}
+
+// F(0) = 0, F(1) = 1, and up to F(186) fits. The last doubling also works out F(n + 1), which for F(186) doesn't fit, but
+// is never used, so that one is allowed to wrap
+pub fn fibonacci(n: u64) -> u128 {
+    assert!(n <= 186, "F({n}) does not fit in a u128");
+
+    doubling(n, 0u128, 1, |&a, &b| (a * (2 * b - a), a.wrapping_mul(a).wrapping_add(b.wrapping_mul(b))), |a, b| a + b)
+}
+
+pub fn fibonacci_mod(n: u64, m: u64) -> u64 {
+    let add = |a: u64, b: u64| ((a as u128 + b as u128) % m as u128) as u64;
+    let mul = |a: u64, b: u64| (a as u128 * b as u128 % m as u128) as u64;
+
+    doubling(n, 0, 1 % m, |&a, &b| (mul(a, add(add(b, b), m - a)), add(mul(a, a), mul(b, b))), |&a, &b| add(a, b))
+}
+
+pub fn fibonacci_big(n: u64) -> BigUint {
+    doubling(n, BigUint::zero(), BigUint::one(), |a, b| (a * &(b * 2 - a), a * a + b * b), |a, b| a + b)
+}
+
+// Collatz sequence lengths, counting the terms from n down to 1 inclusive, remembering every length below the limit
+// so each sequence stops as soon as it meets one already walked
+pub struct Collatz {
+    lengths: Vec<u32>,
+}
+
+impl Collatz {
+    pub fn new(limit: usize) -> Self {
+        let mut lengths = vec![0; limit.max(2)];
+
+        lengths[1] = 1;
+
+        Collatz {lengths}
+    }
+
+    pub fn length(&mut self, n: u64) -> u32 {
+        assert!(n > 0, "the Collatz sequence starts from a positive number");
+
+        let mut path = vec![];
+        let mut current = n;
+
+        let known = loop {
+            if let Some(&length) = self.lengths.get(current as usize).filter(|&&length| length > 0) {
+                break length;
+            }
+
+            path.push(current);
+
+            current = if current.is_multiple_of(2) {
+                current / 2
+            } else {
+                current
+                    .checked_mul(3)
+                    .and_then(|current| current.checked_add(1))
+                    .unwrap_or_else(|| panic!("the Collatz sequence from {n} overflows a u64"))
+            };
+        };
+
+        path.iter().rev().zip(known + 1..).for_each(|(&term, length)| {
+            if let Some(slot) = self.lengths.get_mut(term as usize) {
+                *slot = length;
+            }
+        });
+
+        known + path.len() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fibonacci() {
+        let mut sequence = vec![0u128, 1];
+
+        (2..=186).for_each(|i| sequence.push(sequence[i - 1] + sequence[i - 2]));
+
+        (0..=186).for_each(|n| {
+            assert_eq!(fibonacci(n as u64), sequence[n], "{n}");
+            assert_eq!(fibonacci_big(n as u64).to_u128(), Some(sequence[n]), "{n}");
+            assert_eq!(fibonacci_mod(n as u64, 1_000_000_007) as u128, sequence[n] % 1_000_000_007, "{n}");
+            assert_eq!(fibonacci_mod(n as u64, u64::MAX) as u128, sequence[n] % u64::MAX as u128, "{n}");
+        });
+
+        assert_eq!(fibonacci_mod(5, 1), 0);
+        assert_eq!(fibonacci_big(300).to_string(), "222232244629420445529739893461909967206666939096499764990979600");
+
+        // The first term to reach a thousand digits
+        let first = (1..).find(|&n| fibonacci_big(n).digits().count() >= 1000).unwrap();
+
+        assert_eq!(fibonacci_big(first - 1).digits().count(), 999);
+    }
+
+    #[test]
+    fn test_collatz() {
+        let walk = |mut n: u64| {
+            let mut length = 1;
+
+            while n != 1 {
+                n = if n.is_multiple_of(2) {n / 2} else {3 * n + 1};
+                length += 1;
+            }
+
+            length
+        };
+
+        let mut collatz = Collatz::new(1000);
+
+        assert_eq!(collatz.length(13), 10);
+
+        (1..3000).for_each(|n| assert_eq!(collatz.length(n), walk(n), "{n}"));
+
+        assert_eq!(Collatz::new(0).length(27), 112);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows a u64")]
+    fn test_collatz_overflow() {
+        Collatz::new(0).length(u64::MAX);
+    }
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+
+use aoc_bench::{format_duration, measure, Stats, Table};
+
+use crate::problems::Problem;
+
+#[derive(Debug, Serialize)]
+pub struct ProblemBench {
+    pub problem: u32,
+    pub title: String,
+    pub warmup: usize,
+    pub iterations: usize,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+pub fn bench(problem: &Problem, warmup: usize, iterations: usize) -> ProblemBench {
+    let stats = measure(warmup, iterations, problem.solution);
+
+    ProblemBench {problem: problem.number, title: problem.title(), warmup, iterations, stats}
+}
+
+pub fn table(results: &[ProblemBench]) -> Table {
+    let mut table = Table::new(["Problem", "Title", "Mean", "Median", "Min", "Std dev"]);
+
+    results.iter().for_each(|result| {
+        table.push([
+            result.problem.to_string(),
+            result.title.clone(),
+            format_duration(result.stats.mean),
+            format_duration(result.stats.median),
+            format_duration(result.stats.min),
+            format_duration(result.stats.std_dev),
+        ]);
+    });
+
+    table
+}
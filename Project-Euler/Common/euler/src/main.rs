@@ -0,0 +1,148 @@
+mod bench;
+mod problems;
+
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use aoc_bench::{format_duration, Table};
+
+use problems::{Problem, PROBLEMS};
+
+#[derive(Parser)]
+#[command(name = "euler", about = "Project Euler solution runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single problem, or every problem with --all
+    Run(RunArgs),
+
+    /// Time a problem's solution, or every problem's
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Problem to solve
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    problem: Option<u32>,
+
+    /// Solve every problem in turn and print a summary table
+    #[arg(long)]
+    all: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Markdown,
+    Json,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Problem to benchmark (every problem by default)
+    problem: Option<u32>,
+
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Timed runs per problem
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+fn select_problems(number: Option<u32>) -> Result<Vec<&'static Problem>, String> {
+    match number {
+        Some(number) => Ok(vec![problems::find(number).ok_or_else(|| format!("Problem {number} has not been solved yet"))?]),
+        None => Ok(PROBLEMS.iter().collect()),
+    }
+}
+
+fn solve(problem: &Problem) -> (String, Duration) {
+    let start = Instant::now();
+
+    let answer = (problem.solution)();
+
+    (answer, start.elapsed())
+}
+
+// The answer goes to stdout on its own so it can be piped, the time to stderr
+fn run_problem(number: u32) -> Result<(), String> {
+    let problem = problems::find(number).ok_or_else(|| format!("Problem {number} has not been solved yet"))?;
+
+    let (answer, time) = solve(problem);
+
+    println!("{answer}");
+    eprintln!("Solved in {}", format_duration(time));
+
+    Ok(())
+}
+
+fn run_all() -> Result<(), String> {
+    let mut table = Table::new(["Problem", "Title", "Answer", "Time"]);
+
+    let start = Instant::now();
+
+    PROBLEMS.iter().for_each(|problem| {
+        let (answer, time) = solve(problem);
+
+        table.push([problem.number.to_string(), problem.title(), answer, format_duration(time)]);
+    });
+
+    print!("{table}");
+
+    println!();
+    println!("Total: {}", format_duration(start.elapsed()));
+
+    Ok(())
+}
+
+fn run_bench(args: &BenchArgs) -> Result<(), String> {
+    let results: Vec<bench::ProblemBench> = select_problems(args.problem)?
+        .into_iter()
+        .map(|problem| {
+            eprintln!("Benchmarking problem {}...", problem.number);
+
+            bench::bench(problem, args.warmup, args.iterations as usize)
+        })
+        .collect();
+
+    match args.format {
+        Format::Table => print!("{}", bench::table(&results)),
+        Format::Markdown => print!("{}", bench::table(&results).markdown()),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&results).unwrap()),
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let outcome = match cli.command {
+        Command::Run(args) => match args.problem {
+            Some(problem) => run_problem(problem),
+            None => run_all(),
+        },
+        Command::Bench(args) => run_bench(&args),
+    };
+
+    match outcome {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+
+            ExitCode::FAILURE
+        },
+    }
+}
@@ -0,0 +1,54 @@
+pub struct Problem {
+    pub number: u32,
+    pub directory: &'static str,
+    pub solution: fn() -> String,
+}
+
+impl Problem {
+    pub fn title(&self) -> String {
+        self.directory
+            .splitn(3, '-')
+            .nth(2)
+            .unwrap_or(self.directory)
+            .replace('-', " ")
+    }
+}
+
+macro_rules! problems {
+    ($($number:literal => $solution:path, $directory:literal;)*) => {
+        pub const PROBLEMS: &[Problem] = &[
+            $(
+                Problem {
+                    number: $number,
+                    directory: $directory,
+                    solution: $solution,
+                },
+            )*
+        ];
+    };
+}
+
+problems! {
+    1 => problem_001::solution, "Problem-001-Multiples-of-3-or-5";
+}
+
+pub fn find(number: u32) -> Option<&'static Problem> {
+    PROBLEMS.iter().find(|problem| problem.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::Path;
+
+    #[test]
+    fn test_registry() {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).ancestors().nth(2).unwrap();
+
+        assert!(PROBLEMS.windows(2).all(|pair| pair[0].number < pair[1].number));
+        assert!(PROBLEMS.iter().all(|problem| problem.directory.starts_with(&format!("Problem-{:03}-", problem.number))));
+        assert!(PROBLEMS.iter().all(|problem| workspace.join(problem.directory).is_dir()));
+        assert_eq!(find(1).unwrap().title(), "Multiples of 3 or 5");
+    }
+}
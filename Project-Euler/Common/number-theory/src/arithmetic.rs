@@ -0,0 +1,99 @@
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {0} else {a / gcd(a, b) * b}
+}
+
+// (g, x, y) with a * x + b * y = g = gcd(a, b), in i128 so any pair of u64s fits
+pub fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a.abs(), a.signum(), 0);
+    }
+
+    let (g, x, y) = ext_gcd(b, a % b);
+
+    (g, y, x - (a / b) * y)
+}
+
+// The x with a * x ≡ 1 (mod m), if a and m are coprime
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {return None}
+
+    let (g, x, _) = ext_gcd((a % m) as i128, m as i128);
+
+    if g == 1 {Some(x.rem_euclid(m as i128) as u64)} else {None}
+}
+
+// Through u128, so the product can't overflow whatever the modulus
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+pub fn mod_pow(base: u64, mut exponent: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+
+        base = mul_mod(base, base, m);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(u32::MAX as u64, u32::MAX as u64 - 1), u32::MAX as u64 * (u32::MAX as u64 - 1));
+    }
+
+    #[test]
+    fn test_ext_gcd() {
+        for (a, b) in [(240, 46), (46, 240), (-240, 46), (240, -46), (0, 5), (5, 0), (u64::MAX as i128, 1 << 40)] {
+            let (g, x, y) = ext_gcd(a, b);
+
+            assert_eq!(g, gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64) as i128, "{a}, {b}");
+            assert_eq!(a * x + b * y, g, "{a}, {b}");
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 0), None);
+
+        let m = 1_000_000_007;
+
+        (1..1000).for_each(|a| assert_eq!(mul_mod(a, mod_inverse(a, m).unwrap(), m), 1));
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(7, 0, 13), 1);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+
+        (0..20).for_each(|exponent| assert_eq!(mod_pow(3, exponent, 1_000_003), 3u64.pow(exponent as u32) % 1_000_003));
+    }
+}
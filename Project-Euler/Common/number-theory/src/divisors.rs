@@ -0,0 +1,91 @@
+// Functions of a factorization, as (prime, exponent) pairs like `factorize` gives
+
+pub fn divisor_count(factors: &[(u64, u32)]) -> u64 {
+    factors.iter().map(|&(_, exponent)| exponent as u64 + 1).product()
+}
+
+// σ_k, the sum of the k-th powers of the divisors: each prime power contributes 1 + p^k + p^2k + ... + p^ek
+pub fn sigma(factors: &[(u64, u32)], k: u32) -> u128 {
+    factors
+        .iter()
+        .map(|&(p, exponent)| {
+            let pk = (p as u128).pow(k);
+
+            (0..exponent).fold(1, |sum, _| sum * pk + 1)
+        })
+        .product()
+}
+
+// Every divisor, in increasing order
+pub fn divisors(factors: &[(u64, u32)]) -> Vec<u64> {
+    let mut divisors = factors.iter().fold(vec![1], |divisors, &(p, exponent)| {
+        divisors
+            .iter()
+            .flat_map(|&d| (0..=exponent).scan(d, move |power, i| {
+                if i > 0 {*power *= p}
+
+                Some(*power)
+            }))
+            .collect()
+    });
+
+    divisors.sort();
+
+    divisors
+}
+
+pub fn totient(factors: &[(u64, u32)]) -> u64 {
+    factors.iter().map(|&(p, exponent)| p.pow(exponent - 1) * (p - 1)).product()
+}
+
+// The number of divisors of every n below `limit`, adding each d to all of its multiples
+pub fn divisor_counts(limit: usize) -> Vec<u32> {
+    let mut counts = vec![0; limit];
+
+    (1..limit).for_each(|d| (d..limit).step_by(d).for_each(|m| counts[m] += 1));
+
+    counts
+}
+
+// σ_1 of every n below `limit`, the same way
+pub fn divisor_sums(limit: usize) -> Vec<u64> {
+    let mut sums = vec![0; limit];
+
+    (1..limit).for_each(|d| (d..limit).step_by(d).for_each(|m| sums[m] += d as u64));
+
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{factorize, gcd};
+
+    #[test]
+    fn test_divisor_functions() {
+        let counts = divisor_counts(1000);
+        let sums = divisor_sums(1000);
+
+        (1..1000u64).for_each(|n| {
+            let brute: Vec<u64> = (1..=n).filter(|d| n.is_multiple_of(*d)).collect();
+            let factors = factorize(n);
+
+            assert_eq!(divisors(&factors), brute, "{n}");
+            assert_eq!(divisor_count(&factors), brute.len() as u64, "{n}");
+            assert_eq!(counts[n as usize], brute.len() as u32, "{n}");
+            assert_eq!(sigma(&factors, 0), brute.len() as u128, "{n}");
+            assert_eq!(sigma(&factors, 1), brute.iter().sum::<u64>() as u128, "{n}");
+            assert_eq!(sums[n as usize], brute.iter().sum::<u64>(), "{n}");
+            assert_eq!(sigma(&factors, 2), brute.iter().map(|&d| (d * d) as u128).sum::<u128>(), "{n}");
+            assert_eq!(totient(&factors), (1..=n).filter(|&k| gcd(k, n) == 1).count() as u64, "{n}");
+        });
+    }
+
+    #[test]
+    fn test_large() {
+        // The first triangle number with over 500 divisors
+        assert_eq!(divisor_count(&factorize(76_576_500)), 576);
+        assert_eq!(sigma(&factorize(u64::MAX), 1), 4 * 6 * 18 * 258 * 642 * 65538 * 6_700_418);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::arithmetic::{gcd, mod_pow, mul_mod};
+
+// Testing against the first twelve primes is enough for Miller–Rabin to be exact for every u64
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {return false}
+
+    if let Some(&p) = WITNESSES.iter().find(|&&p| n.is_multiple_of(p)) {
+        return n == p;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    WITNESSES.iter().all(|&a| {
+        let mut x = mod_pow(a, d, n);
+
+        x == 1 || x == n - 1 || (1..s).any(|_| {
+            x = mul_mod(x, x, n);
+
+            x == n - 1
+        })
+    })
+}
+
+// (prime, exponent) pairs, smallest prime first, dividing by every candidate up to √n
+pub fn trial_division(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut p = 2;
+
+    while p <= n / p {
+        let mut exponent = 0;
+
+        while n.is_multiple_of(p) {
+            n /= p;
+            exponent += 1;
+        }
+
+        if exponent > 0 {
+            factors.push((p, exponent));
+        }
+
+        p += if p == 2 {1} else {2};
+    }
+
+    if n > 1 {
+        factors.push((n, 1));
+    }
+
+    factors
+}
+
+// Brent's cycle finding on x -> x² + c, multiplying up a batch of differences before each gcd. When a batch overshoots
+// to a gcd of n, its steps are retried one at a time, and if that still gives n this c has failed
+fn rho(n: u64, c: u64) -> Option<u64> {
+    const BATCH: usize = 128;
+
+    let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+
+    let (mut x, mut y, mut ys) = (0, 2, 2);
+    let (mut q, mut g, mut r) = (1, 1, 1);
+
+    while g == 1 {
+        x = y;
+
+        (0..r).for_each(|_| y = f(y));
+
+        let mut k = 0;
+
+        while k < r && g == 1 {
+            ys = y;
+
+            (0..BATCH.min(r - k)).for_each(|_| {
+                y = f(y);
+                q = mul_mod(q, x.abs_diff(y), n);
+            });
+
+            g = gcd(q, n);
+            k += BATCH;
+        }
+
+        r *= 2;
+    }
+
+    if g == n {
+        g = loop {
+            ys = f(ys);
+
+            let g = gcd(x.abs_diff(ys), n);
+
+            if g > 1 {break g}
+        };
+    }
+
+    if g == n {None} else {Some(g)}
+}
+
+// A factor of the composite `n` other than 1 and n, trying one polynomial after another until one splits it
+pub fn pollard_rho(n: u64) -> u64 {
+    if n.is_multiple_of(2) {return 2}
+
+    (1..).find_map(|c| rho(n, c)).unwrap()
+}
+
+fn split(n: u64, primes: &mut Vec<u64>) {
+    if n == 1 {return}
+
+    if is_prime(n) {
+        primes.push(n);
+
+        return;
+    }
+
+    let d = pollard_rho(n);
+
+    split(d, primes);
+    split(n / d, primes);
+}
+
+// (prime, exponent) pairs, smallest prime first. Small primes are divided out directly, which leaves rho only the
+// large factors it is good at. Zero has no factorization, so like one it gets an empty one
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    if n == 0 {return vec![]}
+
+    let mut primes = vec![];
+
+    for p in 2..100 {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+
+    split(n, &mut primes);
+
+    primes.sort();
+
+    primes
+        .chunk_by(|a, b| a == b)
+        .map(|run| (run[0], run.len() as u32))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_prime() {
+        let trial = |n: u64| n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d));
+
+        (0..10_000).for_each(|n| assert_eq!(is_prime(n), trial(n), "{n}"));
+
+        assert!(is_prime((1 << 61) - 1));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(u64::MAX));
+
+        // Strong pseudoprimes to the first several bases
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(3_825_123_056_546_413_051));
+    }
+
+    #[test]
+    fn test_factorize() {
+        (1..5000).for_each(|n| assert_eq!(factorize(n), trial_division(n), "{n}"));
+
+        assert_eq!(factorize(0), vec![]);
+        assert_eq!(factorize(u64::MAX), vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6_700_417, 1)]);
+        assert_eq!(factorize(4_294_967_291 * 4_294_967_279), vec![(4_294_967_279, 1), (4_294_967_291, 1)]);
+        assert_eq!(factorize(1_000_003u64.pow(3)), vec![(1_000_003, 3)]);
+        assert_eq!(factorize(600_851_475_143), vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]);
+    }
+
+    #[test]
+    fn test_pollard_rho() {
+        for n in [9, 15, 1_000_003 * 1_000_033, 101 * 101, 4_294_967_291 * 65_537] {
+            let d = pollard_rho(n);
+
+            assert!(d > 1 && d < n && n.is_multiple_of(d), "{n}: {d}");
+        }
+    }
+}
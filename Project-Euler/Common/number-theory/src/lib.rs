@@ -0,0 +1,9 @@
+mod arithmetic;
+mod divisors;
+mod factor;
+mod sieve;
+
+pub use arithmetic::{ext_gcd, gcd, lcm, mod_inverse, mod_pow, mul_mod};
+pub use divisors::{divisor_count, divisor_counts, divisor_sums, divisors, sigma, totient};
+pub use factor::{factorize, is_prime, pollard_rho, trial_division};
+pub use sieve::{primes_below, primes_between, LinearSieve};
@@ -0,0 +1,250 @@
+use crate::is_prime;
+
+// Numbers per segment: small enough to stay in cache, large enough that the base primes are worth looping over
+const SEGMENT: u64 = 1 << 16;
+
+// Roughly how many numbers the sieve gets through in the time one Miller–Rabin test takes
+const TEST_COST: u64 = 1 << 15;
+
+// The largest base prime sieved with. Further out than its square, whatever survives the sieve is tested with
+// Miller–Rabin, which keeps the base sieve to a megabyte however close to u64::MAX the window is
+const BASE_LIMIT: u64 = 1 << 20;
+
+fn simple_sieve(limit: u64) -> Vec<u64> {
+    let mut composite = vec![false; limit as usize];
+
+    (2..limit)
+        .filter(|&n| {
+            if composite[n as usize] {return false}
+
+            (n * n..limit).step_by(n as usize).for_each(|m| composite[m as usize] = true);
+
+            true
+        })
+        .collect()
+}
+
+// The first multiple of p to cross off from `start` on, skipping those below p² that a smaller prime already has. None
+// when the next multiple would be past u64::MAX, so the prime has nothing left to cross off
+fn first_multiple(p: u64, start: u64) -> Option<u64> {
+    let square = p.checked_mul(p)?;
+
+    Some(square.max(start.div_ceil(p).checked_mul(p)?))
+}
+
+// Primes in [low, high) by the sieve of Eratosthenes, one segment at a time. The segments and the base primes are
+// both bounded, by SEGMENT and BASE_LIMIT, so memory stays the same however far out the range is. Up to BASE_LIMIT²
+// the base primes reach √high and the sieve alone decides; past it they only weed out the numbers with a small factor
+// and Miller–Rabin settles the rest. A window too narrow to pay for the base primes is tested one number at a time
+pub fn primes_between(low: u64, high: u64) -> Vec<u64> {
+    if high <= low {return vec![]}
+
+    let root = ((high - 1).isqrt() + 1).min(BASE_LIMIT);
+
+    if (high - low).saturating_mul(TEST_COST) < root {
+        return (low..high).filter(|&n| is_prime(n)).collect();
+    }
+
+    let base = simple_sieve(root);
+
+    // Whether the base primes cover every factor a composite below `high` can have
+    let complete = (high - 1).isqrt() < root;
+
+    let mut primes = vec![];
+    let mut start = low.max(2);
+
+    while start < high {
+        let end = start.saturating_add(SEGMENT).min(high);
+
+        let mut composite = vec![false; (end - start) as usize];
+
+        for &p in base.iter().take_while(|&&p| p * p < end) {
+            let Some(first) = first_multiple(p, start) else {continue};
+
+            (first..end).step_by(p as usize).for_each(|m| composite[(m - start) as usize] = true);
+        }
+
+        primes.extend((start..end).filter(|&n| !composite[(n - start) as usize] && (complete || is_prime(n))));
+
+        start = end;
+    }
+
+    primes
+}
+
+pub fn primes_below(limit: u64) -> Vec<u64> {
+    primes_between(2, limit)
+}
+
+// The smallest prime factor of every number below a limit, from a linear sieve that crosses each composite off exactly
+// once, by its smallest prime. Factorizing is then a walk down the table, and multiplicative functions fall out of it
+pub struct LinearSieve {
+    spf: Vec<u32>,
+    primes: Vec<u32>,
+}
+
+impl LinearSieve {
+    pub fn new(limit: u32) -> Self {
+        let mut spf = vec![0; limit as usize];
+        let mut primes: Vec<u32> = vec![];
+
+        for i in 2..limit {
+            if spf[i as usize] == 0 {
+                spf[i as usize] = i;
+                primes.push(i);
+            }
+
+            for &p in &primes {
+                if p > spf[i as usize] || i as u64 * p as u64 >= limit as u64 {break}
+
+                spf[(i * p) as usize] = p;
+            }
+        }
+
+        LinearSieve {spf, primes}
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.spf.len() as u32
+    }
+
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    pub fn smallest_prime_factor(&self, n: u32) -> Option<u32> {
+        (n >= 2).then(|| self.spf[n as usize])
+    }
+
+    pub fn is_prime(&self, n: u32) -> bool {
+        n >= 2 && self.spf[n as usize] == n
+    }
+
+    // (prime, exponent) pairs, smallest prime first, for any n below the limit
+    pub fn factorize(&self, mut n: u32) -> Vec<(u64, u32)> {
+        let mut factors: Vec<(u64, u32)> = vec![];
+
+        while n >= 2 {
+            let p = self.spf[n as usize];
+
+            match factors.last_mut() {
+                Some((last, exponent)) if *last == p as u64 => *exponent += 1,
+                _ => factors.push((p as u64, 1)),
+            }
+
+            n /= p;
+        }
+
+        factors
+    }
+
+    // Each n = p * m with p its smallest prime, so a table entry follows from m's depending on whether p divides m too
+    fn multiplicative<T: Copy>(&self, zero: T, one: T, next: impl Fn(T, u32, bool) -> T) -> Vec<T> {
+        let mut table = vec![zero; self.spf.len()];
+
+        if table.len() > 1 {
+            table[1] = one;
+        }
+
+        for n in 2..self.limit() {
+            let p = self.spf[n as usize];
+            let m = n / p;
+
+            table[n as usize] = next(table[m as usize], p, m.is_multiple_of(p));
+        }
+
+        table
+    }
+
+    // Euler's φ for every n below the limit
+    pub fn totients(&self) -> Vec<u32> {
+        self.multiplicative(0, 1, |phi, p, repeated| if repeated {phi * p} else {phi * (p - 1)})
+    }
+
+    // The Möbius μ for every n below the limit: 0 with a square factor, otherwise -1 to the number of primes
+    pub fn mobius(&self) -> Vec<i8> {
+        self.multiplicative(0, 1, |mu, _, repeated| if repeated {0} else {-mu})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{gcd, is_prime, trial_division};
+
+    #[test]
+    fn test_primes_below() {
+        assert_eq!(primes_below(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes_below(2), vec![]);
+        assert_eq!(primes_below(1_000_000).len(), 78_498);
+    }
+
+    #[test]
+    fn test_primes_between() {
+        // Ranges that start mid segment and run across segment boundaries
+        for (low, high) in [(0, 10), (90, 150), (SEGMENT - 100, 3 * SEGMENT + 7), (1_000_000_000, 1_000_000_000 + 2 * SEGMENT), (5, 5), (10, 3)] {
+            let expected: Vec<u64> = (low..high).filter(|&n| is_prime(n)).collect();
+
+            assert_eq!(primes_between(low, high), expected, "[{low}, {high})");
+        }
+    }
+
+    #[test]
+    fn test_wide_window_near_u64_max() {
+        let (low, high) = (u64::MAX - (1 << 17), u64::MAX);
+
+        let primes = primes_between(low, high);
+
+        assert_eq!(primes, (low..high).filter(|&n| is_prime(n)).collect::<Vec<u64>>());
+        assert_eq!(primes.last(), Some(&18_446_744_073_709_551_557));
+    }
+
+    #[test]
+    fn test_primes_near_u64_max() {
+        let top = |offset: u64| u64::MAX - offset + 1;
+
+        assert_eq!(primes_between(top(200), u64::MAX), [189, 179, 95, 83, 59].map(top));
+
+        // Multiples of 3 end at u64::MAX itself, and 2 has none left past u64::MAX - 1
+        assert_eq!(first_multiple(3, u64::MAX - 1), Some(u64::MAX));
+        assert_eq!(first_multiple(2, u64::MAX), None);
+        assert_eq!(first_multiple(4_294_967_311, 0), None);
+        assert_eq!(first_multiple(7, 30), Some(49));
+    }
+
+    #[test]
+    fn test_linear_sieve() {
+        let sieve = LinearSieve::new(2000);
+
+        assert_eq!(sieve.primes().iter().map(|&p| p as u64).collect::<Vec<u64>>(), primes_below(2000));
+        assert_eq!(sieve.smallest_prime_factor(1), None);
+        assert_eq!(sieve.smallest_prime_factor(91), Some(7));
+
+        (1..2000).for_each(|n| {
+            assert_eq!(sieve.factorize(n), trial_division(n as u64), "{n}");
+            assert_eq!(sieve.is_prime(n), is_prime(n as u64), "{n}");
+        });
+    }
+
+    #[test]
+    fn test_totients_and_mobius() {
+        let sieve = LinearSieve::new(500);
+
+        let totients = sieve.totients();
+        let mobius = sieve.mobius();
+
+        (1..500u32).for_each(|n| {
+            assert_eq!(totients[n as usize], (1..=n).filter(|&k| gcd(k as u64, n as u64) == 1).count() as u32, "{n}");
+
+            let factors = trial_division(n as u64);
+
+            let mu = if factors.iter().any(|&(_, exponent)| exponent > 1) {0} else if factors.len().is_multiple_of(2) {1} else {-1};
+
+            assert_eq!(mobius[n as usize], mu, "{n}");
+        });
+
+        assert!(LinearSieve::new(0).totients().is_empty());
+        assert_eq!(LinearSieve::new(2).mobius(), vec![0, 1]);
+    }
+}
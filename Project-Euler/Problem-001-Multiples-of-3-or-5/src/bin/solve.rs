@@ -1,5 +0,0 @@
-use problem_001::solution;
-
-fn main() {
-    println!("{}", solution());
-}
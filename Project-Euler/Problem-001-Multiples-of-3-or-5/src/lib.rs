@@ -1,12 +1,121 @@
-use std::collections::BTreeSet;
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {a} else {gcd(b, a % b)}
+}
+
+// divisor * (1 + 2 + ... + n) for the n multiples of `divisor` below `limit`, halving whichever of n and n + 1 is even
+// first, or None when it does not fit in a u128
+fn multiples_below(limit: u128, divisor: u128) -> Option<u128> {
+    let n = (limit - 1) / divisor;
+
+    let triangle = if n.is_multiple_of(2) {(n / 2).checked_mul(n + 1)} else {n.checked_mul(n.div_ceil(2))};
+
+    triangle?.checked_mul(divisor)
+}
+
+// The sum of the multiples of `lcm` below `limit` divisible by at least one of `divisors`, by inclusion–exclusion: each
+// divisor adds the multiples of its lcm with `lcm` less those an earlier divisor already counted. Every partial total is
+// then a sum over a subset of the final numbers, so nothing overflows unless the final sum does. An lcm that reaches
+// `limit`, or does not fit in a u128, has no multiples below it, so the divisor is skipped along with everything under it
+fn sum_divisible(limit: u128, lcm: u128, divisors: &[u128]) -> Option<u128> {
+    let mut total: u128 = 0;
+
+    for (i, &divisor) in divisors.iter().enumerate() {
+        let Some(lcm) = (lcm / gcd(lcm, divisor)).checked_mul(divisor).filter(|&lcm| lcm < limit) else {continue};
+
+        let counted = sum_divisible(limit, lcm, &divisors[..i])?;
+
+        total = total.checked_add(multiples_below(limit, lcm)? - counted)?;
+    }
+
+    Some(total)
+}
+
+// The sum of every number below `limit` divisible by at least one of `divisors`
+pub fn sum_of_multiples(limit: u128, divisors: &[u64]) -> u128 {
+    let mut divisors: Vec<u128> = divisors.iter().filter(|&&d| d > 0).map(|&d| d as u128).collect();
+
+    divisors.sort();
+    divisors.dedup();
+
+    let result = sum_divisible(limit, 1, &divisors).expect("The sum of multiples does not fit in a u128");
+
+    return result;
+}
 
 pub fn solution() -> String {
-    let result: u32 = (3..1000)
-        .step_by(3)
-        .chain((5..1000).step_by(5))
-        .collect::<BTreeSet<u32>>()
-        .iter()
-        .sum();
+    let result = sum_of_multiples(1000, &[3, 5]);
 
     return result.to_string();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(limit: u128, divisors: &[u64]) -> u128 {
+        (1..limit)
+            .filter(|n| divisors.iter().any(|&d| d > 0 && n % d as u128 == 0))
+            .sum()
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(solution(), "233168");
+        assert_eq!(sum_of_multiples(10, &[3, 5]), 23);
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let divisor_sets: [&[u64]; 9] = [&[], &[1], &[3, 5], &[4, 6], &[2, 3, 5, 7], &[6, 10, 15], &[7, 7, 0], &[12, 18, 30, 45], &[250]];
+
+        for divisors in divisor_sets {
+            for limit in 0..300 {
+                assert_eq!(sum_of_multiples(limit, divisors), brute_force(limit, divisors), "limit {limit}, divisors {divisors:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_limits() {
+        let n = 10u128.pow(18);
+
+        assert_eq!(sum_of_multiples(n + 1, &[1]), n * (n + 1) / 2);
+        assert_eq!(sum_of_multiples(n, &[u64::MAX]), 0);
+        assert_eq!(sum_of_multiples(u64::MAX as u128 + 1, &[u64::MAX]), u64::MAX as u128);
+
+        let limit = 10u128.pow(19);
+
+        assert_eq!(sum_of_multiples(limit, &[3, 5]), sum_of_multiples(limit, &[3]) + sum_of_multiples(limit, &[5]) - sum_of_multiples(limit, &[15]));
+    }
+
+    #[test]
+    fn test_partial_sums_do_not_overflow() {
+        // Every number below the limit sums to just under u128::MAX, so adding the multiples of 2 on top would overflow
+        let limit = (1u128 << 64) + (1 << 62);
+
+        assert_eq!(sum_of_multiples(limit, &[1, 2]), limit / 2 * (limit - 1));
+    }
+
+    #[test]
+    fn test_lcm_overflow() {
+        // lcm(2^33, 2^33 + 1) is below the limit, but its lcm with u64::MAX does not fit in a u128
+        let (a, b, c) = (1u64 << 33, (1u64 << 33) + 1, u64::MAX);
+        let limit = 1u128 << 67;
+
+        let multiples = |d: u128| {
+            let n = (limit - 1) / d;
+
+            d * n * (n + 1) / 2
+        };
+
+        let expected = multiples(a as u128) + multiples(b as u128) + multiples(c as u128) - multiples(a as u128 * b as u128);
+
+        assert_eq!(sum_of_multiples(limit, &[a, b, c]), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in a u128")]
+    fn test_sum_too_large() {
+        sum_of_multiples(u128::MAX, &[u64::MAX - 1, u64::MAX]);
+    }
+}